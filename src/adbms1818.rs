@@ -0,0 +1,78 @@
+//! Device-specific types for [ADBMS1818](<https://www.analog.com/en/products/adbms1818.html>)
+//!
+//! ADBMS1818 is the pin- and protocol-compatible successor of LTC6813. The conversion times, the timings of the
+//! isoSPI interface and the constants for measuring the internal device parameters (sum of cells, die temperature)
+//! in the [datasheet](<https://www.analog.com/media/en/technical-documentation/data-sheets/adbms1818.pdf>) are
+//! identical to the LTC6813 ones. So registers, channels and timing tables are shared with [LTC6813].
+use crate::config::ConfigurationRegisterB;
+use crate::ltc6813::{ltc6813_types, LTC6813};
+use crate::monitor::{ConversionTiming, DeviceTypes, NoPolling, LTC681X};
+use embedded_hal::spi::SpiDevice;
+
+ltc6813_types!(
+    ADBMS1818,
+    adbms1818,
+    "See [datasheet](<https://www.analog.com/media/en/technical-documentation/data-sheets/adbms1818.pdf>)"
+);
+
+/// Device type of ADBMS1818
+///
+/// Pin- and protocol-compatible successor of LTC6813
+#[cfg_attr(test, derive(Debug))]
+pub struct ADBMS1818 {}
+
+impl DeviceTypes for ADBMS1818 {
+    type CellSelection = CellSelection;
+    type GPIOSelection = GPIOSelection;
+    type Register = Register;
+    type Channel = Channel;
+    type Cell = Cell;
+    type GPIO = GPIO;
+
+    const CELL_COUNT: usize = 18;
+    const GPIO_COUNT: usize = 9;
+
+    const ALL_CELLS: Self::CellSelection = CellSelection::All;
+    const ALL_GPIOS: Self::GPIOSelection = GPIOSelection::All;
+
+    const WAKE_UP_TIME: u32 = LTC6813::WAKE_UP_TIME;
+    const READY_TIME: u32 = LTC6813::READY_TIME;
+
+    const CELL_GPIO_CONV_TIMING: ConversionTiming = LTC6813::CELL_GPIO_CONV_TIMING;
+    const CELL_SUM_CONV_TIMING: Option<ConversionTiming> = LTC6813::CELL_SUM_CONV_TIMING;
+
+    const DISCHARGE_MUTE: bool = true;
+
+    const OVERLAP_TEST_REG_1: Option<Self::Register> = Some(Register::CellVoltageC);
+    const OVERLAP_TEST_REG_2: Option<Self::Register> = Some(Register::CellVoltageE);
+
+    const REG_STATUS_A: Self::Register = Register::StatusA;
+    const REG_STATUS_B: Self::Register = Register::StatusB;
+    const REG_AUX_D: Option<Self::Register> = Some(Register::AuxiliaryD);
+
+    const REG_CONF_A: Self::Register = Register::ConfigurationA;
+    const REG_CONF_B: Option<Self::Register> = Some(Register::ConfigurationB);
+    const REG_CONF_A_MASK: [u8; 6] = [0b0000_0101, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    const REG_CONF_B_MASK: [u8; 6] = [0b1111_0000, 0b0111_1111, 0x00, 0x00, 0x00, 0x00];
+
+    const REG_COMM: Self::Register = Register::Comm;
+    const REG_PWM: Option<Self::Register> = Some(Register::PWM);
+    const REG_S_CONTROL: Option<Self::Register> = Some(Register::SControl);
+    const REG_PSB: Option<Self::Register> = Some(Register::PWMSControlB);
+
+    const TOTAL_VOLTAGE_FACTOR: u32 = LTC6813::TOTAL_VOLTAGE_FACTOR;
+    const INTERNAL_TEMP_GAIN: i32 = LTC6813::INTERNAL_TEMP_GAIN;
+    const INTERNAL_TEMP_OFFSET: i16 = LTC6813::INTERNAL_TEMP_OFFSET;
+}
+
+impl<B, const L: usize> LTC681X<B, NoPolling, ADBMS1818, L>
+where
+    B: SpiDevice<u8>,
+{
+    /// Creates a client instant for ADBMS1818 variant
+    pub fn adbms1818(bus: B) -> Self {
+        LTC681X::new(bus)
+    }
+}
+
+impl ConfigurationRegisterB for ADBMS1818 {}
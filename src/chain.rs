@@ -0,0 +1,589 @@
+//! # Heterogeneous daisy chains
+//!
+//! [LTC681X] fixes one device type for the whole daisy chain. [MixedChain] takes a device type per chain
+//! position instead, e.g. for a chain of LTC6813 devices with an LTC6811 on the last module.
+//!
+//! Commands are issued to all devices based on an LTC6813 client, as its command set covers the union of all
+//! supported device types. The register data of each position is parsed according to the channel and register
+//! mapping of its own device type. Registers a position lacks (e.g. cell register groups E/F or CFGRB of LTC6811)
+//! are skipped for that position, so their data is neither checked nor returned.
+//!
+//! ## Conversion and reading voltages
+//!
+//! The returned conversion time is the maximum of all device types in chain.
+//! Voltages are returned per position, typed by the device type of the position.
+//!
+//!````
+//! use ltc681x::chain::{ChainDevice, ChainVoltages, MixedChain};
+//! use ltc681x::example::ExampleSPIDevice;
+//! use ltc681x::monitor::{ADCMode, LTC681X};
+//! use ltc681x::{ltc6811, ltc6813};
+//!
+//! let client = LTC681X::ltc6813(ExampleSPIDevice::default());
+//! let mut chain = MixedChain::new(client, [ChainDevice::LTC6813, ChainDevice::LTC6811]);
+//!
+//! let timing = chain.start_conv_cells(ADCMode::Normal, false).unwrap();
+//! assert_eq!(2343, timing.regular);
+//!
+//! let voltages = chain.read_cell_voltages().unwrap();
+//!
+//! match &voltages[0] {
+//!     ChainVoltages::LTC6813(voltages) => {
+//!         assert_eq!(18, voltages.len());
+//!         assert_eq!(ltc6813::Channel::Cell1, voltages[0].channel);
+//!         assert_eq!(24979, voltages[0].voltage);
+//!     }
+//!     _ => unreachable!(),
+//! }
+//!
+//! match &voltages[1] {
+//!     ChainVoltages::LTC6811(voltages) => {
+//!         assert_eq!(12, voltages.len());
+//!         assert_eq!(ltc6811::Channel::Cell1, voltages[0].channel);
+//!         assert_eq!(24979, voltages[0].voltage);
+//!     }
+//!     _ => unreachable!(),
+//! }
+//!````
+//!
+//! ## Configuration
+//!
+//! The configuration of each position is typed by its device type as well. Positions lacking CFGRB receive zeros
+//! when writing CFGRB, which are ignored by these devices.
+//!
+//!````
+//! use ltc681x::chain::{ChainDevice, MixedChain};
+//! use ltc681x::config::Configuration;
+//! use ltc681x::example::ExampleSPIDevice;
+//! use ltc681x::ltc6810::LTC6810;
+//! use ltc681x::ltc6813::LTC6813;
+//! use ltc681x::monitor::LTC681X;
+//!
+//! let client = LTC681X::ltc6813(ExampleSPIDevice::default());
+//! let mut chain = MixedChain::new(client, [ChainDevice::LTC6813, ChainDevice::LTC6810]);
+//!
+//! let mut config_ltc6813: Configuration<LTC6813> = Configuration::default();
+//! config_ltc6813.set_ov_comp_voltage(4_200_000).unwrap();
+//!
+//! let config_ltc6810: Configuration<LTC6810> = Configuration::default();
+//!
+//! chain.write_configuration([config_ltc6813.into(), config_ltc6810.into()]).unwrap();
+//!
+//! let config = chain.read_configuration().unwrap();
+//! assert_eq!(ChainDevice::LTC6810, config[1].device());
+//!````
+//!
+//! ## Other commands
+//!
+//! All commands without device specific results (e.g. wake-up, clearing registers or muting) are available via the
+//! underlying [client](MixedChain::client).
+use crate::adbms1818::ADBMS1818;
+use crate::config::Configuration;
+use crate::ltc6804::LTC6804;
+use crate::ltc6810::LTC6810;
+use crate::ltc6811::LTC6811;
+use crate::ltc6812::LTC6812;
+use crate::ltc6813;
+use crate::ltc6813::LTC6813;
+use crate::monitor::{
+    ADCMode, CommandTime, DeviceTypes, Error, GroupedRegisterIndex, LTC681XClient, PollClient, PollMethod,
+    RegisterLocator, ToCommandTiming, Voltage, LTC681X,
+};
+use crate::pec15::PEC15;
+use embedded_hal::spi::SpiDevice;
+use heapless::Vec;
+
+/// Cell voltage register groups A-F, indexed by grouped register index
+const CELL_REGISTERS: [ltc6813::Register; 6] = [
+    ltc6813::Register::CellVoltageA,
+    ltc6813::Register::CellVoltageB,
+    ltc6813::Register::CellVoltageC,
+    ltc6813::Register::CellVoltageD,
+    ltc6813::Register::CellVoltageE,
+    ltc6813::Register::CellVoltageF,
+];
+
+/// Auxiliary register groups A-D, indexed by grouped register index
+const AUX_REGISTERS: [ltc6813::Register; 4] = [
+    ltc6813::Register::AuxiliaryA,
+    ltc6813::Register::AuxiliaryB,
+    ltc6813::Register::AuxiliaryC,
+    ltc6813::Register::AuxiliaryD,
+];
+
+/// Device type of a single daisy chain position
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ChainDevice {
+    ADBMS1818,
+    LTC6804,
+    LTC6810,
+    LTC6811,
+    LTC6812,
+    LTC6813,
+}
+
+/// Voltages of a single daisy chain position, typed by the device type of the position
+pub enum ChainVoltages {
+    ADBMS1818(Vec<Voltage<ADBMS1818>, 18>),
+    LTC6804(Vec<Voltage<LTC6804>, 18>),
+    LTC6810(Vec<Voltage<LTC6810>, 18>),
+    LTC6811(Vec<Voltage<LTC6811>, 18>),
+    LTC6812(Vec<Voltage<LTC6812>, 18>),
+    LTC6813(Vec<Voltage<LTC6813>, 18>),
+}
+
+/// Configuration of a single daisy chain position, typed by the device type of the position
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ChainConfiguration {
+    ADBMS1818(Configuration<ADBMS1818>),
+    LTC6804(Configuration<LTC6804>),
+    LTC6810(Configuration<LTC6810>),
+    LTC6811(Configuration<LTC6811>),
+    LTC6812(Configuration<LTC6812>),
+    LTC6813(Configuration<LTC6813>),
+}
+
+/// Client for daisy chains mixing different device types
+pub struct MixedChain<B, P, const L: usize>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+{
+    /// Client issuing the commands to all devices in daisy chain
+    client: LTC681X<B, P, LTC6813, L>,
+
+    /// Device type of each daisy chain position
+    devices: [ChainDevice; L],
+}
+
+impl<B, P, const L: usize> MixedChain<B, P, L>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+{
+    /// Creates a new chain client. Commands are issued by the given LTC6813 client, which may use any poll method.
+    ///
+    /// # Arguments
+    ///
+    /// * `client`: LTC6813 client used for issuing the commands
+    /// * `devices`: Device type of each daisy chain position
+    pub fn new(client: LTC681X<B, P, LTC6813, L>, devices: [ChainDevice; L]) -> Self {
+        Self { client, devices }
+    }
+
+    /// Returns the device type of each daisy chain position
+    pub fn devices(&self) -> &[ChainDevice; L] {
+        &self.devices
+    }
+
+    /// Returns the underlying client, e.g. for issuing commands without device specific results
+    pub fn client(&mut self) -> &mut LTC681X<B, P, LTC6813, L> {
+        &mut self.client
+    }
+
+    /// Starts ADC conversion of all cells of all devices in daisy chain
+    ///
+    /// Returns the maximum conversion time of all device types in daisy chain
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    /// * `dcp`: True if discharge is permitted during conversion
+    pub fn start_conv_cells(&mut self, mode: ADCMode, dcp: bool) -> Result<CommandTime, Error<B>> {
+        self.client.start_conv_cells(mode, ltc6813::CellSelection::All, dcp)?;
+
+        Ok(self.max_timing(|device| device.cell_timing(mode)))
+    }
+
+    /// Starts ADC conversion of all GPIOs of all devices in daisy chain
+    ///
+    /// Returns the maximum conversion time of all device types in daisy chain
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    pub fn start_conv_gpio(&mut self, mode: ADCMode) -> Result<CommandTime, Error<B>> {
+        self.client.start_conv_gpio(mode, ltc6813::GPIOSelection::All)?;
+
+        Ok(self.max_timing(|device| device.gpio_timing(mode)))
+    }
+
+    /// Reads the voltages of all cells, one item per daisy chain position
+    ///
+    /// Cell register groups are just read if at least one position contains them.
+    pub fn read_cell_voltages(&mut self) -> Result<Vec<ChainVoltages, L>, Error<B>> {
+        let masks = self.devices.map(|device| device.cell_registers());
+        let registers = self.read_register_groups(&CELL_REGISTERS, masks)?;
+
+        Ok(self
+            .devices
+            .iter()
+            .zip(registers.iter())
+            .map(|(device, registers)| device.map_cells(registers))
+            .collect())
+    }
+
+    /// Reads the voltages of all GPIOs (incl. reference voltages), one item per daisy chain position
+    ///
+    /// Auxiliary register groups are just read if at least one position contains them.
+    pub fn read_gpio_voltages(&mut self) -> Result<Vec<ChainVoltages, L>, Error<B>> {
+        let masks = self.devices.map(|device| device.gpio_registers());
+        let registers = self.read_register_groups(&AUX_REGISTERS, masks)?;
+
+        Ok(self
+            .devices
+            .iter()
+            .zip(registers.iter())
+            .map(|(device, registers)| device.map_gpios(registers))
+            .collect())
+    }
+
+    /// Writes the configuration, one array item per daisy chain position
+    ///
+    /// CFGRB is just written if at least one position contains it. Positions lacking CFGRB receive zeros.
+    ///
+    /// Returns [Error::DeviceMismatch] without writing anything, in case the device type of a configuration does not
+    /// match the device type of its position.
+    pub fn write_configuration(&mut self, config: [ChainConfiguration; L]) -> Result<(), Error<B>> {
+        let mut register_a = [[0x0u8; 6]; L];
+        let mut register_b = [[0x0u8; 6]; L];
+
+        for (position, item) in config.iter().enumerate() {
+            if self.devices[position] != item.device() {
+                return Err(Error::DeviceMismatch(position));
+            }
+
+            register_a[position] = item.register_a();
+
+            if let Some(register) = item.register_b() {
+                register_b[position] = register;
+            }
+        }
+
+        self.client.write_register(ltc6813::Register::ConfigurationA, register_a)?;
+
+        if self.devices.iter().any(|device| device.has_conf_b()) {
+            self.client.write_register(ltc6813::Register::ConfigurationB, register_b)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads the configuration, one item per daisy chain position
+    ///
+    /// CFGRB is just read if at least one position contains it. Positions lacking CFGRB use its default values.
+    pub fn read_configuration(&mut self) -> Result<Vec<ChainConfiguration, L>, Error<B>> {
+        let register_a = self.read_checked_frames(ltc6813::Register::ConfigurationA, [true; L])?;
+
+        let has_conf_b = self.devices.map(|device| device.has_conf_b());
+        let register_b = if has_conf_b.iter().any(|item| *item) {
+            self.read_checked_frames(ltc6813::Register::ConfigurationB, has_conf_b)?
+        } else {
+            [[0x0; 6]; L]
+        };
+
+        Ok(self
+            .devices
+            .iter()
+            .enumerate()
+            .map(|(position, device)| device.configuration(register_a[position], register_b[position]))
+            .collect())
+    }
+
+    /// Returns the maximum command time of all device types in daisy chain
+    fn max_timing<F: Fn(&ChainDevice) -> CommandTime>(&self, timing: F) -> CommandTime {
+        self.devices.iter().map(timing).fold(CommandTime::new(0, 0), |max, time| {
+            CommandTime::new(max.regular.max(time.regular), max.alternative.max(time.alternative))
+        })
+    }
+
+    /// Reads the given register groups. Each register group is just read if at least one position contains it.
+    /// Masks are holding one bit per register group (grouped register index), which is set if the position
+    /// contains the register group.
+    fn read_register_groups<const N: usize>(
+        &mut self,
+        registers: &[ltc6813::Register; N],
+        masks: [u8; L],
+    ) -> Result<[[[u16; 3]; 6]; L], Error<B>> {
+        let mut result = [[[0x0; 3]; 6]; L];
+
+        for (index, register) in registers.iter().enumerate() {
+            let included = masks.map(|mask| mask & (1 << index) != 0);
+            if !included.iter().any(|item| *item) {
+                continue;
+            }
+
+            let data = self.read_checked_frames(*register, included)?;
+            for (position, item) in data.iter().enumerate() {
+                for slot in 0..3 {
+                    result[position][index][slot] = item[slot * 2] as u16 | (item[slot * 2 + 1] as u16) << 8;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Reads the given register and verifies the PEC of all included positions.
+    /// Data of positions not included is set to zero.
+    fn read_checked_frames(
+        &mut self,
+        register: ltc6813::Register,
+        included: [bool; L],
+    ) -> Result<[[u8; 6]; L], Error<B>> {
+        let frames = self.client.read_register_frames(register)?;
+
+        let mut result = [[0x0; 6]; L];
+        for (position, frame) in frames.iter().enumerate() {
+            if !included[position] {
+                continue;
+            }
+
+            let pec = PEC15::calc(&frame[0..6]);
+            if pec[0] != frame[6] || pec[1] != frame[7] {
+                return Err(Error::ChecksumMismatch);
+            }
+
+            result[position].copy_from_slice(&frame[0..6]);
+        }
+
+        Ok(result)
+    }
+}
+
+impl<B, P, const L: usize> PollClient for MixedChain<B, P, L>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    LTC681X<B, P, LTC6813, L>: PollClient,
+{
+    type Error = <LTC681X<B, P, LTC6813, L> as PollClient>::Error;
+
+    /// See [PollClient::adc_ready]
+    fn adc_ready(&mut self) -> Result<bool, Self::Error> {
+        self.client.adc_ready()
+    }
+}
+
+impl ChainDevice {
+    /// Returns the conversion time of all cells
+    fn cell_timing(&self, mode: ADCMode) -> CommandTime {
+        match self {
+            ChainDevice::ADBMS1818 => ADBMS1818::ALL_CELLS.to_conv_command_timing(mode),
+            ChainDevice::LTC6804 => LTC6804::ALL_CELLS.to_conv_command_timing(mode),
+            ChainDevice::LTC6810 => LTC6810::ALL_CELLS.to_conv_command_timing(mode),
+            ChainDevice::LTC6811 => LTC6811::ALL_CELLS.to_conv_command_timing(mode),
+            ChainDevice::LTC6812 => LTC6812::ALL_CELLS.to_conv_command_timing(mode),
+            ChainDevice::LTC6813 => LTC6813::ALL_CELLS.to_conv_command_timing(mode),
+        }
+    }
+
+    /// Returns the conversion time of all GPIOs
+    fn gpio_timing(&self, mode: ADCMode) -> CommandTime {
+        match self {
+            ChainDevice::ADBMS1818 => ADBMS1818::ALL_GPIOS.to_conv_command_timing(mode),
+            ChainDevice::LTC6804 => LTC6804::ALL_GPIOS.to_conv_command_timing(mode),
+            ChainDevice::LTC6810 => LTC6810::ALL_GPIOS.to_conv_command_timing(mode),
+            ChainDevice::LTC6811 => LTC6811::ALL_GPIOS.to_conv_command_timing(mode),
+            ChainDevice::LTC6812 => LTC6812::ALL_GPIOS.to_conv_command_timing(mode),
+            ChainDevice::LTC6813 => LTC6813::ALL_GPIOS.to_conv_command_timing(mode),
+        }
+    }
+
+    /// Returns the bit mask of cell register groups the device contains
+    fn cell_registers(&self) -> u8 {
+        match self {
+            ChainDevice::ADBMS1818 => register_mask(ADBMS1818::ALL_CELLS),
+            ChainDevice::LTC6804 => register_mask(LTC6804::ALL_CELLS),
+            ChainDevice::LTC6810 => register_mask(LTC6810::ALL_CELLS),
+            ChainDevice::LTC6811 => register_mask(LTC6811::ALL_CELLS),
+            ChainDevice::LTC6812 => register_mask(LTC6812::ALL_CELLS),
+            ChainDevice::LTC6813 => register_mask(LTC6813::ALL_CELLS),
+        }
+    }
+
+    /// Returns the bit mask of auxiliary register groups the device contains
+    fn gpio_registers(&self) -> u8 {
+        match self {
+            ChainDevice::ADBMS1818 => register_mask(ADBMS1818::ALL_GPIOS),
+            ChainDevice::LTC6804 => register_mask(LTC6804::ALL_GPIOS),
+            ChainDevice::LTC6810 => register_mask(LTC6810::ALL_GPIOS),
+            ChainDevice::LTC6811 => register_mask(LTC6811::ALL_GPIOS),
+            ChainDevice::LTC6812 => register_mask(LTC6812::ALL_GPIOS),
+            ChainDevice::LTC6813 => register_mask(LTC6813::ALL_GPIOS),
+        }
+    }
+
+    /// Maps the cell register groups to the cell voltages of the device
+    fn map_cells(&self, registers: &[[u16; 3]; 6]) -> ChainVoltages {
+        match self {
+            ChainDevice::ADBMS1818 => ChainVoltages::ADBMS1818(map_voltages(ADBMS1818::ALL_CELLS, registers)),
+            ChainDevice::LTC6804 => ChainVoltages::LTC6804(map_voltages(LTC6804::ALL_CELLS, registers)),
+            ChainDevice::LTC6810 => ChainVoltages::LTC6810(map_voltages(LTC6810::ALL_CELLS, registers)),
+            ChainDevice::LTC6811 => ChainVoltages::LTC6811(map_voltages(LTC6811::ALL_CELLS, registers)),
+            ChainDevice::LTC6812 => ChainVoltages::LTC6812(map_voltages(LTC6812::ALL_CELLS, registers)),
+            ChainDevice::LTC6813 => ChainVoltages::LTC6813(map_voltages(LTC6813::ALL_CELLS, registers)),
+        }
+    }
+
+    /// Maps the auxiliary register groups to the GPIO voltages of the device
+    fn map_gpios(&self, registers: &[[u16; 3]; 6]) -> ChainVoltages {
+        match self {
+            ChainDevice::ADBMS1818 => ChainVoltages::ADBMS1818(map_voltages(ADBMS1818::ALL_GPIOS, registers)),
+            ChainDevice::LTC6804 => ChainVoltages::LTC6804(map_voltages(LTC6804::ALL_GPIOS, registers)),
+            ChainDevice::LTC6810 => ChainVoltages::LTC6810(map_voltages(LTC6810::ALL_GPIOS, registers)),
+            ChainDevice::LTC6811 => ChainVoltages::LTC6811(map_voltages(LTC6811::ALL_GPIOS, registers)),
+            ChainDevice::LTC6812 => ChainVoltages::LTC6812(map_voltages(LTC6812::ALL_GPIOS, registers)),
+            ChainDevice::LTC6813 => ChainVoltages::LTC6813(map_voltages(LTC6813::ALL_GPIOS, registers)),
+        }
+    }
+
+    /// Returns true if the device contains a second configuration register group (CFGRB)
+    fn has_conf_b(&self) -> bool {
+        match self {
+            ChainDevice::ADBMS1818 => ADBMS1818::REG_CONF_B.is_some(),
+            ChainDevice::LTC6804 => LTC6804::REG_CONF_B.is_some(),
+            ChainDevice::LTC6810 => LTC6810::REG_CONF_B.is_some(),
+            ChainDevice::LTC6811 => LTC6811::REG_CONF_B.is_some(),
+            ChainDevice::LTC6812 => LTC6812::REG_CONF_B.is_some(),
+            ChainDevice::LTC6813 => LTC6813::REG_CONF_B.is_some(),
+        }
+    }
+
+    /// Creates the configuration of the device based on the given register values
+    /// Register B is ignored if the device has no such register.
+    fn configuration(&self, register_a: [u8; 6], register_b: [u8; 6]) -> ChainConfiguration {
+        let register_b = self.has_conf_b().then_some(register_b);
+
+        match self {
+            ChainDevice::ADBMS1818 => ChainConfiguration::ADBMS1818(to_configuration(register_a, register_b)),
+            ChainDevice::LTC6804 => ChainConfiguration::LTC6804(to_configuration(register_a, register_b)),
+            ChainDevice::LTC6810 => ChainConfiguration::LTC6810(to_configuration(register_a, register_b)),
+            ChainDevice::LTC6811 => ChainConfiguration::LTC6811(to_configuration(register_a, register_b)),
+            ChainDevice::LTC6812 => ChainConfiguration::LTC6812(to_configuration(register_a, register_b)),
+            ChainDevice::LTC6813 => ChainConfiguration::LTC6813(to_configuration(register_a, register_b)),
+        }
+    }
+}
+
+impl ChainVoltages {
+    /// Returns the device type of the voltages
+    pub fn device(&self) -> ChainDevice {
+        match self {
+            ChainVoltages::ADBMS1818(_) => ChainDevice::ADBMS1818,
+            ChainVoltages::LTC6804(_) => ChainDevice::LTC6804,
+            ChainVoltages::LTC6810(_) => ChainDevice::LTC6810,
+            ChainVoltages::LTC6811(_) => ChainDevice::LTC6811,
+            ChainVoltages::LTC6812(_) => ChainDevice::LTC6812,
+            ChainVoltages::LTC6813(_) => ChainDevice::LTC6813,
+        }
+    }
+}
+
+impl ChainConfiguration {
+    /// Returns the device type of the configuration
+    pub fn device(&self) -> ChainDevice {
+        match self {
+            ChainConfiguration::ADBMS1818(_) => ChainDevice::ADBMS1818,
+            ChainConfiguration::LTC6804(_) => ChainDevice::LTC6804,
+            ChainConfiguration::LTC6810(_) => ChainDevice::LTC6810,
+            ChainConfiguration::LTC6811(_) => ChainDevice::LTC6811,
+            ChainConfiguration::LTC6812(_) => ChainDevice::LTC6812,
+            ChainConfiguration::LTC6813(_) => ChainDevice::LTC6813,
+        }
+    }
+
+    /// Returns the value of configuration register A
+    fn register_a(&self) -> [u8; 6] {
+        match self {
+            ChainConfiguration::ADBMS1818(config) => config.register_a,
+            ChainConfiguration::LTC6804(config) => config.register_a,
+            ChainConfiguration::LTC6810(config) => config.register_a,
+            ChainConfiguration::LTC6811(config) => config.register_a,
+            ChainConfiguration::LTC6812(config) => config.register_a,
+            ChainConfiguration::LTC6813(config) => config.register_a,
+        }
+    }
+
+    /// Returns the value of configuration register B, None in case the device has no such register
+    fn register_b(&self) -> Option<[u8; 6]> {
+        if !self.device().has_conf_b() {
+            return None;
+        }
+
+        Some(match self {
+            ChainConfiguration::ADBMS1818(config) => config.register_b,
+            ChainConfiguration::LTC6804(config) => config.register_b,
+            ChainConfiguration::LTC6810(config) => config.register_b,
+            ChainConfiguration::LTC6811(config) => config.register_b,
+            ChainConfiguration::LTC6812(config) => config.register_b,
+            ChainConfiguration::LTC6813(config) => config.register_b,
+        })
+    }
+}
+
+impl From<Configuration<ADBMS1818>> for ChainConfiguration {
+    fn from(config: Configuration<ADBMS1818>) -> Self {
+        ChainConfiguration::ADBMS1818(config)
+    }
+}
+
+impl From<Configuration<LTC6804>> for ChainConfiguration {
+    fn from(config: Configuration<LTC6804>) -> Self {
+        ChainConfiguration::LTC6804(config)
+    }
+}
+
+impl From<Configuration<LTC6810>> for ChainConfiguration {
+    fn from(config: Configuration<LTC6810>) -> Self {
+        ChainConfiguration::LTC6810(config)
+    }
+}
+
+impl From<Configuration<LTC6811>> for ChainConfiguration {
+    fn from(config: Configuration<LTC6811>) -> Self {
+        ChainConfiguration::LTC6811(config)
+    }
+}
+
+impl From<Configuration<LTC6812>> for ChainConfiguration {
+    fn from(config: Configuration<LTC6812>) -> Self {
+        ChainConfiguration::LTC6812(config)
+    }
+}
+
+impl From<Configuration<LTC6813>> for ChainConfiguration {
+    fn from(config: Configuration<LTC6813>) -> Self {
+        ChainConfiguration::LTC6813(config)
+    }
+}
+
+/// Returns the bit mask of register groups (grouped register index) used by the given locator
+fn register_mask<T: DeviceTypes, R: RegisterLocator<T>>(locator: R) -> u8 {
+    locator
+        .get_locations()
+        .fold(0, |mask, address| mask | (1 << address.register.to_index()))
+}
+
+/// Maps the register groups to voltages according to the register locations of the device type
+fn map_voltages<T: DeviceTypes, R: RegisterLocator<T>>(locator: R, registers: &[[u16; 3]; 6]) -> Vec<Voltage<T>, 18> {
+    locator
+        .get_locations()
+        .map(|address| Voltage {
+            channel: address.channel,
+            voltage: registers[address.register.to_index()][address.slot],
+        })
+        .collect()
+}
+
+/// Creates the configuration of the device type. Default values are kept for register B, if not given.
+fn to_configuration<T: DeviceTypes>(register_a: [u8; 6], register_b: Option<[u8; 6]>) -> Configuration<T> {
+    let mut config = Configuration::default();
+    config.register_a = register_a;
+
+    if let Some(register_b) = register_b {
+        config.register_b = register_b;
+    }
+
+    config
+}
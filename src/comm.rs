@@ -0,0 +1,618 @@
+//! # Communication with external devices via COMM register
+//!
+//! The LTC681X devices may act as I2C or SPI master on their GPIO pins (e.g. GPIO3-GPIO5 of LTC6813).
+//! Data is written to the COMM register group (WRCOMM command), clocked out by the STCOMM command and
+//! the received data is read back from the COMM register (RDCOMM command).
+//!
+//! Each STCOMM command transmits up to three bytes, longer transfers are split into multiple
+//! COMM cycles by this module. Only the selected device of the daisy chain transmits data, all other
+//! devices are instructed to not transmit anything.
+//!
+//! ## I2C
+//!
+//! [I2CMaster] implements the [embedded_hal::i2c::I2c] trait for one device of the daisy chain.
+//! So existing I2C drivers (e.g. EEPROMs or temperature sensors) may be used through the monitor.
+//! The I2C pins must not be pulled down by the GPIO configuration.
+//!
+//!````
+//! use embedded_hal::i2c::I2c;
+//! use ltc681x::comm::I2CMaster;
+//! use ltc681x::example::ExampleSPIDevice;
+//! use ltc681x::ltc6813::LTC6813;
+//! use ltc681x::monitor::LTC681X;
+//!
+//! let spi_bus = ExampleSPIDevice::default();
+//! let mut client: LTC681X<_, _, LTC6813, 3> = LTC681X::ltc6813(spi_bus);
+//!
+//! // I2C bus of the second device in daisy chain
+//! let mut i2c = I2CMaster::new(&mut client, 1).unwrap();
+//!
+//! // Reads one byte of register 0x10 of I2C slave 0x50
+//! let mut buffer = [0x0];
+//! i2c.write_read(0x50, &[0x10], &mut buffer).unwrap();
+//! assert_eq!(0x2A, buffer[0]);
+//!````
+//!
+//! ## SPI
+//!
+//! [SPIMaster] implements the [embedded_hal::spi::SpiDevice] trait for one device of the daisy chain,
+//! e.g. for accessing external ADCs or multiplexers. CSBM is held low for the whole transaction.
+//! Data is only read back from the COMM register, if the current COMM cycle contains any read byte.
+//! The given delay is used for delay operations within transactions.
+//!
+//!````
+//! use embedded_hal::spi::SpiDevice;
+//! use ltc681x::comm::SPIMaster;
+//! use ltc681x::example::{ExampleDelay, ExampleSPIDevice};
+//! use ltc681x::ltc6813::LTC6813;
+//! use ltc681x::monitor::LTC681X;
+//!
+//! let spi_bus = ExampleSPIDevice::default();
+//! let mut client: LTC681X<_, _, LTC6813, 3> = LTC681X::ltc6813(spi_bus);
+//!
+//! // SPI bus of the first device in daisy chain
+//! let mut spi = SPIMaster::new(&mut client, 0, ExampleDelay {}).unwrap();
+//!
+//! // Transfers longer than three bytes are split into multiple COMM cycles
+//! spi.write(&[0x01, 0x02, 0x03, 0x04]).unwrap();
+//!
+//! let mut buffer = [0x0; 2];
+//! spi.transfer(&mut buffer, &[0x80, 0x00]).unwrap();
+//! assert_eq!([0x2A, 0x2A], buffer);
+//!````
+use crate::monitor::{DeviceTypes, LTC681XClient, PollMethod, LTC681X};
+use core::fmt::{Debug, Formatter};
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::{I2c, NoAcknowledgeSource};
+use embedded_hal::spi::SpiDevice;
+use embedded_hal::{i2c, spi};
+use heapless::Vec;
+
+/// I2C initial control bits (ICOM): START condition
+const I2C_START: u8 = 0b0110;
+
+/// I2C initial control bits (ICOM): STOP condition
+const I2C_STOP: u8 = 0b0001;
+
+/// I2C initial control bits (ICOM): No condition, just transmits the data byte
+const I2C_BLANK: u8 = 0b0000;
+
+/// I2C initial control bits (ICOM): Nothing is transmitted
+const I2C_NO_TRANSMIT: u8 = 0b0111;
+
+/// I2C final control bits (FCOM): Master acknowledges the received byte
+const I2C_MASTER_ACK: u8 = 0b0000;
+
+/// I2C final control bits (FCOM): Master does not acknowledge, so the slave may acknowledge written bytes
+const I2C_MASTER_NACK: u8 = 0b1000;
+
+/// I2C final control bits (FCOM): Master does not acknowledge followed by STOP condition
+const I2C_MASTER_NACK_STOP: u8 = 0b1001;
+
+/// Bit of read back I2C final control bits (FCOM), which is set in case the slave did not acknowledge
+const I2C_SLAVE_NACK_BIT: u8 = 0b1000;
+
+/// Byte of COMM register in case nothing is transmitted on I2C bus
+const I2C_IDLE: CommByte = CommByte::new(I2C_NO_TRANSMIT, 0xFF, I2C_MASTER_NACK_STOP);
+
+/// SPI initial control bits (ICOM): CSBM low
+const SPI_CS_LOW: u8 = 0b1000;
+
+/// SPI initial control bits (ICOM): Nothing is transmitted
+const SPI_NO_TRANSMIT: u8 = 0b1111;
+
+/// SPI final control bits (FCOM): CSBM stays low
+const SPI_CS_HOLD: u8 = 0b0000;
+
+/// SPI final control bits (FCOM): CSBM is released (high)
+const SPI_CS_RELEASE: u8 = 0b1001;
+
+/// Byte of COMM register in case nothing is transmitted on SPI bus
+const SPI_IDLE: CommByte = CommByte::new(SPI_NO_TRANSMIT, 0xFF, SPI_CS_RELEASE);
+
+/// Error of COMM based communication
+pub enum Error<B: SpiDevice<u8>> {
+    /// Error of the underlying LTC681X client
+    ClientError(crate::monitor::Error<B>),
+
+    /// I2C slave did not acknowledge the address or a data byte
+    NoAcknowledge(NoAcknowledgeSource),
+
+    /// The given device index is out of daisy chain bounds
+    InvalidDevice(usize),
+}
+
+/// Data byte of COMM register including the initial (ICOM) and final (FCOM) control bits
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct CommByte {
+    /// Initial control bits (4 bits)
+    icom: u8,
+
+    /// Transmitted or received data
+    data: u8,
+
+    /// Final control bits (4 bits)
+    fcom: u8,
+}
+
+impl CommByte {
+    const fn new(icom: u8, data: u8, fcom: u8) -> Self {
+        Self { icom, data, fcom }
+    }
+
+    /// Returns the two register bytes
+    fn encode(&self) -> [u8; 2] {
+        [
+            (self.icom << 4) | (self.data >> 4),
+            (self.data << 4) | (self.fcom & 0x0F),
+        ]
+    }
+
+    /// Parses the two register bytes
+    fn decode(bytes: &[u8]) -> Self {
+        Self {
+            icom: bytes[0] >> 4,
+            data: (bytes[0] << 4) | (bytes[1] >> 4),
+            fcom: bytes[1] & 0x0F,
+        }
+    }
+}
+
+/// Transmits up to three bytes on the bus of the given device, all other devices are not transmitting
+fn transmit<B, P, T, const L: usize>(
+    client: &mut LTC681X<B, P, T, L>,
+    device: usize,
+    bytes: &[CommByte],
+    idle: CommByte,
+) -> Result<(), crate::monitor::Error<B>>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+{
+    let mut data = [[0x0_u8; 6]; L];
+
+    for (device_index, register) in data.iter_mut().enumerate() {
+        for slot in 0..3 {
+            let byte = match bytes.get(slot) {
+                Some(byte) if device_index == device => *byte,
+                _ => idle,
+            };
+
+            register[slot * 2..slot * 2 + 2].copy_from_slice(&byte.encode());
+        }
+    }
+
+    client.write_register(T::REG_COMM, data)?;
+    client.start_comm()
+}
+
+/// Returns the read back COMM register content of the given device
+fn receive<B, P, T, const L: usize>(
+    client: &mut LTC681X<B, P, T, L>,
+    device: usize,
+) -> Result<[CommByte; 3], crate::monitor::Error<B>>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+{
+    let response = client.read_comm()?;
+
+    Ok([
+        CommByte::decode(&response[device][0..2]),
+        CommByte::decode(&response[device][2..4]),
+        CommByte::decode(&response[device][4..6]),
+    ])
+}
+
+/// I2C master of one device in daisy chain
+pub struct I2CMaster<'a, B, P, T, const L: usize>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+{
+    /// LTC681X client
+    client: &'a mut LTC681X<B, P, T, L>,
+
+    /// Index of the device in daisy chain
+    device: usize,
+}
+
+impl<'a, B, P, T, const L: usize> I2CMaster<'a, B, P, T, L>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+{
+    /// Creates a new I2C master for the device with the given daisy chain index
+    ///
+    /// Returns [Error::InvalidDevice] in case the device index is out of daisy chain bounds
+    pub fn new(client: &'a mut LTC681X<B, P, T, L>, device: usize) -> Result<Self, Error<B>> {
+        if device >= client.chain_length() {
+            return Err(Error::InvalidDevice(device));
+        }
+
+        Ok(Self { client, device })
+    }
+
+    /// Sends a STOP condition for releasing the I2C bus
+    fn stop(&mut self) -> Result<(), Error<B>> {
+        transmit(
+            self.client,
+            self.device,
+            &[CommByte::new(I2C_STOP, 0xFF, I2C_MASTER_NACK_STOP)],
+            I2C_IDLE,
+        )
+        .map_err(Error::ClientError)
+    }
+}
+
+/// Single byte of an I2C transaction
+#[derive(Copy, Clone)]
+enum I2CSlot {
+    /// Address byte, true in case of read access
+    Address(bool),
+
+    /// Written data byte
+    Write(u8),
+
+    /// Read data byte, stored to the given operation index and buffer index
+    Read(usize, usize),
+}
+
+impl I2CSlot {
+    /// Returns the COMM byte of this slot, based on the following slot
+    fn to_comm_byte(self, address: u8, next: Option<I2CSlot>) -> CommByte {
+        let fcom = match (self, next) {
+            (_, None) => I2C_MASTER_NACK_STOP,
+            (I2CSlot::Read(_, _), Some(I2CSlot::Read(_, _))) => I2C_MASTER_ACK,
+            _ => I2C_MASTER_NACK,
+        };
+
+        match self {
+            I2CSlot::Address(read) => CommByte::new(I2C_START, (address << 1) | read as u8, fcom),
+            I2CSlot::Write(data) => CommByte::new(I2C_BLANK, data, fcom),
+            I2CSlot::Read(_, _) => CommByte::new(I2C_BLANK, 0xFF, fcom),
+        }
+    }
+}
+
+/// Iterates over the bytes of I2C operations
+/// Consecutive operations of the same type are merged, otherwise a repeated START + address is inserted
+struct I2CCursor {
+    /// Index of current operation
+    operation: usize,
+
+    /// Index of current byte within operation, None in case address needs to be sent first
+    position: Option<usize>,
+}
+
+impl I2CCursor {
+    fn new() -> Self {
+        Self {
+            operation: 0,
+            position: None,
+        }
+    }
+
+    /// Returns the current slot or None if all operations are processed
+    fn peek(&self, operations: &[i2c::Operation<'_>]) -> Option<I2CSlot> {
+        let operation = operations.get(self.operation)?;
+
+        Some(match (self.position, operation) {
+            (None, operation) => I2CSlot::Address(is_read(operation)),
+            (Some(index), i2c::Operation::Write(buffer)) => I2CSlot::Write(buffer[index]),
+            (Some(index), i2c::Operation::Read(_)) => I2CSlot::Read(self.operation, index),
+        })
+    }
+
+    /// Moves to the next slot
+    fn advance(&mut self, operations: &[i2c::Operation<'_>]) {
+        self.position = Some(self.position.map_or(0, |index| index + 1));
+
+        while let Some(operation) = operations.get(self.operation) {
+            match self.position {
+                Some(index) if index >= operation_len(operation) => {}
+                _ => return,
+            }
+
+            self.operation += 1;
+            self.position = match operations.get(self.operation) {
+                Some(next) if is_read(next) != is_read(operation) => None,
+                _ => Some(0),
+            };
+        }
+    }
+}
+
+fn is_read(operation: &i2c::Operation<'_>) -> bool {
+    matches!(operation, i2c::Operation::Read(_))
+}
+
+fn operation_len(operation: &i2c::Operation<'_>) -> usize {
+    match operation {
+        i2c::Operation::Read(buffer) => buffer.len(),
+        i2c::Operation::Write(buffer) => buffer.len(),
+    }
+}
+
+impl<B, P, T, const L: usize> i2c::ErrorType for I2CMaster<'_, B, P, T, L>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+{
+    type Error = Error<B>;
+}
+
+impl<B, P, T, const L: usize> I2c for I2CMaster<'_, B, P, T, L>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+{
+    fn transaction(&mut self, address: u8, operations: &mut [i2c::Operation<'_>]) -> Result<(), Self::Error> {
+        let mut cursor = I2CCursor::new();
+
+        while cursor.peek(operations).is_some() {
+            let mut slots: Vec<I2CSlot, 3> = Vec::new();
+            let mut bytes: Vec<CommByte, 3> = Vec::new();
+
+            while let (false, Some(slot)) = (slots.is_full(), cursor.peek(operations)) {
+                cursor.advance(operations);
+
+                let _ = bytes.push(slot.to_comm_byte(address, cursor.peek(operations)));
+                let _ = slots.push(slot);
+            }
+
+            transmit(self.client, self.device, &bytes, I2C_IDLE).map_err(Error::ClientError)?;
+            let response = receive(self.client, self.device).map_err(Error::ClientError)?;
+
+            for (slot, byte) in slots.iter().zip(response.iter()) {
+                let source = match slot {
+                    I2CSlot::Address(_) => NoAcknowledgeSource::Address,
+                    I2CSlot::Write(_) => NoAcknowledgeSource::Data,
+                    I2CSlot::Read(operation, index) => {
+                        if let i2c::Operation::Read(buffer) = &mut operations[*operation] {
+                            buffer[*index] = byte.data;
+                        }
+                        continue;
+                    }
+                };
+
+                if byte.fcom & I2C_SLAVE_NACK_BIT != 0 {
+                    // Releasing the bus, if STOP condition was not sent yet
+                    if cursor.peek(operations).is_some() {
+                        self.stop()?;
+                    }
+
+                    return Err(Error::NoAcknowledge(source));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// SPI master of one device in daisy chain
+pub struct SPIMaster<'a, B, P, T, D, const L: usize>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+    D: DelayNs,
+{
+    /// LTC681X client
+    client: &'a mut LTC681X<B, P, T, L>,
+
+    /// Index of the device in daisy chain
+    device: usize,
+
+    /// Delay used for delay operations within transactions
+    delay: D,
+}
+
+impl<'a, B, P, T, D, const L: usize> SPIMaster<'a, B, P, T, D, L>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+    D: DelayNs,
+{
+    /// Creates a new SPI master for the device with the given daisy chain index
+    ///
+    /// Returns [Error::InvalidDevice] in case the device index is out of daisy chain bounds
+    pub fn new(client: &'a mut LTC681X<B, P, T, L>, device: usize, delay: D) -> Result<Self, Error<B>> {
+        if device >= client.chain_length() {
+            return Err(Error::InvalidDevice(device));
+        }
+
+        Ok(Self { client, device, delay })
+    }
+}
+
+/// Single step of a SPI transaction
+#[derive(Copy, Clone)]
+enum SPISlot {
+    /// Transferred data byte, received byte is stored to the given operation index and buffer index
+    Byte(u8, Option<(usize, usize)>),
+
+    /// Delay in nanoseconds
+    Delay(u32),
+}
+
+/// Iterates over the bytes and delays of SPI operations
+#[derive(Copy, Clone)]
+struct SPICursor {
+    /// Index of current operation
+    operation: usize,
+
+    /// Index of current byte within operation
+    index: usize,
+}
+
+impl SPICursor {
+    fn new(operations: &[spi::Operation<'_, u8>]) -> Self {
+        let mut cursor = Self { operation: 0, index: 0 };
+        cursor.skip_finished(operations);
+        cursor
+    }
+
+    /// Returns the current slot or None if all operations are processed
+    fn peek(&self, operations: &[spi::Operation<'_, u8>]) -> Option<SPISlot> {
+        let target = Some((self.operation, self.index));
+
+        Some(match operations.get(self.operation)? {
+            spi::Operation::Read(_) => SPISlot::Byte(0xFF, target),
+            spi::Operation::Write(buffer) => SPISlot::Byte(buffer[self.index], None),
+            spi::Operation::Transfer(read, write) => SPISlot::Byte(
+                write.get(self.index).copied().unwrap_or(0xFF),
+                target.filter(|_| self.index < read.len()),
+            ),
+            spi::Operation::TransferInPlace(buffer) => SPISlot::Byte(buffer[self.index], target),
+            spi::Operation::DelayNs(ns) => SPISlot::Delay(*ns),
+        })
+    }
+
+    /// Moves to the next slot
+    fn advance(&mut self, operations: &[spi::Operation<'_, u8>]) {
+        self.index += 1;
+        self.skip_finished(operations);
+    }
+
+    /// Returns true if any data byte is left, so CS needs to be kept low
+    fn has_bytes(&self, operations: &[spi::Operation<'_, u8>]) -> bool {
+        let mut cursor = *self;
+
+        loop {
+            match cursor.peek(operations) {
+                Some(SPISlot::Byte(_, _)) => return true,
+                Some(SPISlot::Delay(_)) => cursor.advance(operations),
+                None => return false,
+            }
+        }
+    }
+
+    /// Skips finished or empty operations
+    fn skip_finished(&mut self, operations: &[spi::Operation<'_, u8>]) {
+        while let Some(operation) = operations.get(self.operation) {
+            let len = match operation {
+                spi::Operation::Read(buffer) => buffer.len(),
+                spi::Operation::Write(buffer) => buffer.len(),
+                spi::Operation::Transfer(read, write) => read.len().max(write.len()),
+                spi::Operation::TransferInPlace(buffer) => buffer.len(),
+                spi::Operation::DelayNs(_) => 1,
+            };
+
+            if self.index < len {
+                return;
+            }
+
+            self.operation += 1;
+            self.index = 0;
+        }
+    }
+}
+
+impl<B, P, T, D, const L: usize> spi::ErrorType for SPIMaster<'_, B, P, T, D, L>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+    D: DelayNs,
+{
+    type Error = Error<B>;
+}
+
+impl<B, P, T, D, const L: usize> SpiDevice<u8> for SPIMaster<'_, B, P, T, D, L>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+    D: DelayNs,
+{
+    fn transaction(&mut self, operations: &mut [spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+        let mut cursor = SPICursor::new(operations);
+
+        loop {
+            let mut targets: Vec<Option<(usize, usize)>, 3> = Vec::new();
+            let mut bytes: Vec<CommByte, 3> = Vec::new();
+
+            while let (false, Some(SPISlot::Byte(data, target))) = (bytes.is_full(), cursor.peek(operations)) {
+                cursor.advance(operations);
+
+                let fcom = if cursor.has_bytes(operations) {
+                    SPI_CS_HOLD
+                } else {
+                    SPI_CS_RELEASE
+                };
+
+                let _ = bytes.push(CommByte::new(SPI_CS_LOW, data, fcom));
+                let _ = targets.push(target);
+            }
+
+            if !bytes.is_empty() {
+                transmit(self.client, self.device, &bytes, SPI_IDLE).map_err(Error::ClientError)?;
+
+                // COMM register is just read back if any received data is needed
+                if targets.iter().any(Option::is_some) {
+                    let response = receive(self.client, self.device).map_err(Error::ClientError)?;
+
+                    for (target, byte) in targets.iter().zip(response.iter()) {
+                        if let Some((operation, index)) = *target {
+                            store_received(&mut operations[operation], index, byte.data);
+                        }
+                    }
+                }
+
+                continue;
+            }
+
+            match cursor.peek(operations) {
+                Some(SPISlot::Delay(ns)) => {
+                    self.delay.delay_ns(ns);
+                    cursor.advance(operations);
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Stores the received byte in the read buffer of the given operation
+fn store_received(operation: &mut spi::Operation<'_, u8>, index: usize, data: u8) {
+    match operation {
+        spi::Operation::Read(buffer) => buffer[index] = data,
+        spi::Operation::Transfer(buffer, _) => buffer[index] = data,
+        spi::Operation::TransferInPlace(buffer) => buffer[index] = data,
+        _ => {}
+    }
+}
+
+impl<B: SpiDevice<u8>> i2c::Error for Error<B> {
+    fn kind(&self) -> i2c::ErrorKind {
+        match self {
+            Error::ClientError(_) | Error::InvalidDevice(_) => i2c::ErrorKind::Other,
+            Error::NoAcknowledge(source) => i2c::ErrorKind::NoAcknowledge(*source),
+        }
+    }
+}
+
+impl<B: SpiDevice<u8>> spi::Error for Error<B> {
+    fn kind(&self) -> spi::ErrorKind {
+        spi::ErrorKind::Other
+    }
+}
+
+impl<B: SpiDevice<u8>> Debug for Error<B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::ClientError(error) => f.debug_tuple("ClientError").field(error).finish(),
+            Error::NoAcknowledge(source) => f.debug_tuple("NoAcknowledge").field(source).finish(),
+            Error::InvalidDevice(device) => f.debug_tuple("InvalidDevice").field(device).finish(),
+        }
+    }
+}
@@ -45,3 +45,33 @@ pub static CMD_W_CONF_A: [u8; 4] = [0x00, 0x1, 0x3D, 0x6E];
 
 /// Precomputed write command for configuration register group B
 pub static CMD_W_CONF_B: [u8; 4] = [0x00, 0x24, 0xB1, 0x9E];
+
+/// Precomputed read command for COMM register group
+pub static CMD_R_COMM: [u8; 4] = [0x07, 0x22, 0x32, 0xD6];
+
+/// Precomputed write command for COMM register group
+pub static CMD_W_COMM: [u8; 4] = [0x07, 0x21, 0x24, 0xB2];
+
+/// Precomputed start command for COMM communication (STCOMM)
+pub static CMD_ST_COMM: [u8; 4] = [0x07, 0x23, 0xB9, 0xE4];
+
+/// Precomputed read command for PWM register group
+pub static CMD_R_PWM: [u8; 4] = [0x00, 0x22, 0x9D, 0x56];
+
+/// Precomputed write command for PWM register group
+pub static CMD_W_PWM: [u8; 4] = [0x00, 0x20, 0x00, 0x00];
+
+/// Precomputed read command for S control register group
+pub static CMD_R_SCTRL: [u8; 4] = [0x00, 0x16, 0xC1, 0xBA];
+
+/// Precomputed write command for S control register group
+pub static CMD_W_SCTRL: [u8; 4] = [0x00, 0x14, 0x5C, 0xEC];
+
+/// Precomputed read command for PWM/S control register group B
+pub static CMD_R_PSB: [u8; 4] = [0x00, 0x1E, 0x29, 0xB4];
+
+/// Precomputed write command for PWM/S control register group B
+pub static CMD_W_PSB: [u8; 4] = [0x00, 0x1C, 0xB4, 0xE2];
+
+/// Precomputed read command for serial ID register group (LTC6810 only)
+pub static CMD_R_SID: [u8; 4] = [0x00, 0x2C, 0x59, 0x90];
@@ -7,16 +7,16 @@
 //! For full details s. [Configuration] struct.
 //!
 //!````
-//! use ltc681x::config::{Cell, Configuration};
+//! use ltc681x::config::Configuration;
 //! use ltc681x::example::ExampleSPIDevice;
-//! use ltc681x::ltc6813::LTC6813;
+//! use ltc681x::ltc6813::{Cell, LTC6813};
 //! use ltc681x::monitor::{LTC681X, LTC681XClient};
 //!
 //! // Single LTC6813 device
 //! let spi_bus = ExampleSPIDevice::default();
 //! let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(spi_bus);
 //!
-//! let mut config = Configuration::default();
+//! let mut config: Configuration<LTC6813> = Configuration::default();
 //!
 //! // Set over-voltage limit to 4.25 V
 //! config.set_ov_comp_voltage(4_250_000).unwrap();
@@ -35,7 +35,7 @@
 //!
 //! Writing to multiple devices in daisy-chain is supported, by providing an array item per device:
 //! ````
-//!# use ltc681x::config::{Cell, Configuration};
+//!# use ltc681x::config::Configuration;
 //!# use ltc681x::example::ExampleSPIDevice;
 //!# use ltc681x::ltc6813::LTC6813;
 //!# use ltc681x::monitor::{LTC681X, LTC681XClient};
@@ -45,7 +45,7 @@
 //! // 3 devices in daisy chain
 //! let mut client: LTC681X<_, _, LTC6813, 3> = LTC681X::ltc6813(spi_bus);
 //!
-//! let mut config = [
+//! let mut config: [Configuration<LTC6813>; 3] = [
 //!     Configuration::default(),
 //!     Configuration::default(),
 //!     Configuration::default()
@@ -58,19 +58,172 @@
 //! client.write_configuration(config).unwrap();
 //! ````
 //!
-use core::fmt::{Display, Formatter};
+//! ## Reading the configuration
+//!
+//! The configuration held by the device may be read back and inspected using getters:
+//! ````
+//!# use ltc681x::config::DischargeTimeout;
+//!# use ltc681x::example::ExampleSPIDevice;
+//!# use ltc681x::ltc6813::{Cell, LTC6813};
+//!# use ltc681x::monitor::{LTC681X, LTC681XClient};
+//!#
+//!# let spi_bus = ExampleSPIDevice::default();
+//! let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(spi_bus);
+//!
+//! let config = client.read_configuration().unwrap();
+//!
+//! assert_eq!(4_200_000, config[0].ov_comp_voltage());
+//! assert_eq!(3_000_000, config[0].uv_comp_voltage());
+//! assert!(config[0].cell_discharged(Cell::Cell3));
+//! assert_eq!(DischargeTimeout::OneMinute, config[0].discharge_timeout());
+//! ````
+//!
+//! ## Verified writing
+//!
+//! The written configuration may be verified by reading it back. Only writable bits are compared,
+//! in case of a mismatch writing is retried the given number of times.
+//! ````
+//!# use ltc681x::config::{Configuration, DischargeTimeout};
+//!# use ltc681x::example::ExampleSPIDevice;
+//!# use ltc681x::ltc6813::{Cell, LTC6813};
+//!# use ltc681x::monitor::{Error, LTC681X, LTC681XClient};
+//!#
+//!# let spi_bus = ExampleSPIDevice::default();
+//! let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(spi_bus);
+//!
+//! let mut config: Configuration<LTC6813> = Configuration::default();
+//! config.enable_reference_power();
+//! config.set_ov_comp_voltage(4_200_000).unwrap();
+//! config.set_uv_comp_voltage(3_000_000).unwrap();
+//! config.discharge_cell(Cell::Cell3);
+//! config.set_discharge_timeout(DischargeTimeout::OneMinute);
+//!
+//! // Writes the configuration, retrying up to two times
+//! client.write_configuration_verified([config.clone()], 2).unwrap();
+//!
+//! // Device index is reported, if read back configuration does not match
+//! config.discharge_cell(Cell::Cell4);
+//! match client.write_configuration_verified([config], 2) {
+//!     Err(Error::VerificationFailed(device_index)) => assert_eq!(0, device_index),
+//!     _ => panic!("Verification should fail"),
+//! }
+//! ````
+//!
+//! ## Device-specific settings
+//!
+//! The configuration is typed per device, so cells and GPIOs are limited to the ones of the device type.
+//! Settings of the second configuration register are only available for LTC6812 and LTC6813 ([ConfigurationRegisterB]),
+//! while the LTC6810 offers its own settings located in its single configuration register.
+//! ````
+//!# use ltc681x::config::{Configuration, DigitalRedundancyPath};
+//!# use ltc681x::example::ExampleSPIDevice;
+//!# use ltc681x::ltc6810::{self, LTC6810};
+//!# use ltc681x::ltc6813::{self, LTC6813};
+//!# use ltc681x::monitor::{LTC681X, LTC681XClient};
+//!#
+//! let mut config: Configuration<LTC6813> = Configuration::default();
+//! config.discharge_cell(ltc6813::Cell::Cell18);
+//! config.enable_gpio_pull_down(ltc6813::GPIO::GPIO9);
+//! config.set_digital_redundancy_path(DigitalRedundancyPath::ADC2);
+//!
+//! let mut client: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(ExampleSPIDevice::default());
+//!
+//! let mut config: Configuration<LTC6810> = Configuration::default();
+//! config.set_ov_comp_voltage(4_200_000).unwrap();
+//! config.discharge_cell(ltc6810::Cell::Cell2);
+//! config.discharge_s0();
+//! config.enable_multi_calibration();
+//!
+//! client.write_configuration([config]).unwrap();
+//! ````
+//!
+//! Unsupported settings are rejected at compile time:
+//! ````compile_fail
+//!# use ltc681x::config::Configuration;
+//!# use ltc681x::ltc6811::{self, LTC6811};
+//!#
+//! let mut config: Configuration<LTC6811> = Configuration::default();
+//! config.discharge_cell(ltc6811::Cell::Cell18);
+//! ````
+//!
+//! ## PWM discharge
+//!
+//! Besides fully turning on the discharge, a PWM duty cycle may be set per cell. The PWM setting
+//! only applies to cells, whose discharge is turned on by the configuration.
+//! On LTC6812 and LTC6813 the settings of cells 13-18 are transparently written to the PSB register group.
+//! ````
+//!# use ltc681x::config::PWMConfiguration;
+//!# use ltc681x::example::ExampleSPIDevice;
+//!# use ltc681x::ltc6813::{Channel, LTC6813};
+//!# use ltc681x::monitor::{LTC681X, LTC681XClient};
+//!#
+//!# let spi_bus = ExampleSPIDevice::default();
+//! let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(spi_bus);
+//!
+//! // Discharge cell 1 with ~50 % duty cycle, all other cells with 100 % (default)
+//! let mut pwm = PWMConfiguration::default();
+//! pwm.set_duty_cycle(Channel::Cell1, 8).unwrap();
+//! client.write_pwm([pwm]).unwrap();
+//!
+//! let pwm = client.read_pwm().unwrap();
+//! assert_eq!(Some(8), pwm[0].duty_cycle(Channel::Cell1));
+//! assert_eq!(Some(15), pwm[0].duty_cycle(Channel::Cell2));
+//! ````
+//!
+//! ## S pin control
+//!
+//! The S pins may drive pulses to external balancing circuits, as long as the discharge of the cell
+//! is turned off. Pulsing is started by the STSCTRL command.
+//! ````
+//!# use ltc681x::config::{SControlConfiguration, SPinControl};
+//!# use ltc681x::example::ExampleSPIDevice;
+//!# use ltc681x::ltc6813::{Channel, LTC6813};
+//!# use ltc681x::monitor::{LTC681X, LTC681XClient};
+//!#
+//!# let spi_bus = ExampleSPIDevice::default();
+//! let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(spi_bus);
+//!
+//! let mut s_control = SControlConfiguration::default();
+//! s_control.set_control(Channel::Cell1, SPinControl::Pulses(3)).unwrap();
+//! s_control.set_control(Channel::Cell2, SPinControl::Low).unwrap();
+//! client.write_s_control([s_control]).unwrap();
+//!
+//! // Starts sending the pulses
+//! client.start_s_control().unwrap();
+//!
+//! let s_control = client.read_s_control().unwrap();
+//! assert_eq!(Some(SPinControl::Pulses(3)), s_control[0].control(Channel::Cell1));
+//! assert_eq!(Some(SPinControl::Low), s_control[0].control(Channel::Cell2));
+//! assert_eq!(Some(SPinControl::High), s_control[0].control(Channel::Cell3));
+//!
+//! // Resets all S pins to driven high
+//! client.clear_s_control().unwrap();
+//! ````
+//!
+use crate::ltc6810::LTC6810;
+use crate::monitor::{ChannelIndex, DeviceTypes};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
 
 /// Abstracted configuration of configuration register(s)
-#[derive(Debug, Clone)]
-pub struct Configuration {
+///
+/// Cells and GPIOs are typed per device, so only settings physically available on the device type can be
+/// configured. Settings located in configuration register B are only available for device types implementing
+/// [ConfigurationRegisterB].
+pub struct Configuration<T: DeviceTypes> {
     /// Computed value of register A
     pub(crate) register_a: [u8; 6],
 
     /// Computed value of register B,
     pub(crate) register_b: [u8; 6],
+
+    device_types: PhantomData<T>,
 }
 
-impl Default for Configuration {
+/// Marker for device types, which have a second configuration register group (CFGRB)
+pub trait ConfigurationRegisterB: DeviceTypes {}
+
+impl<T: DeviceTypes> Default for Configuration<T> {
     fn default() -> Self {
         Self {
             register_a: [
@@ -89,13 +242,14 @@ impl Default for Configuration {
                 0b0000_0000,
                 0b0000_0000,
             ],
+            device_types: PhantomData,
         }
     }
 }
 
-/// GPIO pins of LTC681X device.
-/// Depending on the device type, not all pins may be available.
-/// Configuring a pin that is not physically available has no effect.
+/// GPIO pins of the LTC681X device family.
+/// Device-specific pins (e.g. [ltc6813::GPIO](crate::ltc6813::GPIO)) are converted into this type for locating the
+/// configuration bits.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum GPIO {
     GPIO1,
@@ -109,9 +263,9 @@ pub enum GPIO {
     GPIO9,
 }
 
-/// Cell indexes of the LTC681X device.
-/// Depending on the device type, not all cells may be available.
-/// Configuring a cell that is not physically available has no effect.
+/// Cell indexes of the LTC681X device family.
+/// Device-specific cells (e.g. [ltc6813::Cell](crate::ltc6813::Cell)) are converted into this type for locating the
+/// configuration bits.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum Cell {
     Cell1,
@@ -179,10 +333,219 @@ impl Display for VoltageOutOfRangeError {
     }
 }
 
-impl Configuration {
+/// Error in case of an invalid PWM duty cycle setting
+#[derive(Debug, PartialEq, Eq)]
+pub enum DutyCycleError {
+    /// Channel is not a cell or the cell has no PWM setting
+    InvalidCell,
+
+    /// Duty cycle exceeds the maximum value of 15
+    OutOfRange,
+}
+
+impl Display for DutyCycleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DutyCycleError::InvalidCell => write!(f, "Channel has no PWM duty cycle setting"),
+            DutyCycleError::OutOfRange => write!(f, "Duty cycle overflows 4-bit integer"),
+        }
+    }
+}
+
+/// PWM duty cycle configuration of the cell discharge (PWM register group)
+///
+/// Duty cycle is set per cell in 4-bit steps, 0 = discharge off, 15 = 100 % (default).
+/// The PWM setting only applies to cells, which discharge is turned on by [Configuration::discharge_cell].
+pub struct PWMConfiguration<T: DeviceTypes> {
+    /// Computed value of PWM register (byte 0-5) + PWM part of PSB register (byte 6-8)
+    pub(crate) register: [u8; 9],
+
+    device_types: PhantomData<T>,
+}
+
+impl<T: DeviceTypes> Default for PWMConfiguration<T> {
+    fn default() -> Self {
+        Self {
+            register: [0xFF; 9],
+            device_types: PhantomData,
+        }
+    }
+}
+
+impl<T: DeviceTypes> PWMConfiguration<T> {
+    /// Sets the PWM duty cycle (0-15) of the given cell
+    pub fn set_duty_cycle(&mut self, cell: T::Channel, duty_cycle: u8) -> Result<(), DutyCycleError> {
+        let (index, shift) = locate_cell_setting::<T>(cell).ok_or(DutyCycleError::InvalidCell)?;
+
+        if duty_cycle > 0x0F {
+            return Err(DutyCycleError::OutOfRange);
+        }
+
+        self.register[index] &= !(0x0F << shift);
+        self.register[index] |= duty_cycle << shift;
+
+        Ok(())
+    }
+
+    /// Returns the PWM duty cycle (0-15) of the given cell. None if the channel has no PWM setting.
+    pub fn duty_cycle(&self, cell: T::Channel) -> Option<u8> {
+        let (index, shift) = locate_cell_setting::<T>(cell)?;
+        Some((self.register[index] >> shift) & 0x0F)
+    }
+}
+
+/// Returns the register byte index and bit shift of the 4-bit setting of the given cell
+/// Used for PWM and S control register groups, which share the same layout.
+/// Settings of cells 13-18 are located in PSB register group.
+fn locate_cell_setting<T: DeviceTypes>(cell: T::Channel) -> Option<(usize, u8)> {
+    let cell_index = cell.to_cell_index()?;
+
+    if cell_index >= 12 && T::REG_PSB.is_none() {
+        return None;
+    }
+
+    Some((cell_index / 2, (cell_index % 2) as u8 * 4))
+}
+
+impl<T: DeviceTypes> Clone for PWMConfiguration<T> {
+    fn clone(&self) -> Self {
+        Self {
+            register: self.register,
+            device_types: PhantomData,
+        }
+    }
+}
+
+impl<T: DeviceTypes> PartialEq<Self> for PWMConfiguration<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.register == other.register
+    }
+}
+
+impl<T: DeviceTypes> Eq for PWMConfiguration<T> {}
+
+impl<T: DeviceTypes> Debug for PWMConfiguration<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PWMConfiguration").field("register", &self.register).finish()
+    }
+}
+
+/// Output of a S pin controlled by the S control register group
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum SPinControl {
+    /// S pin is driven high (default)
+    High,
+
+    /// S pin sends the given number of pulses (1-7)
+    Pulses(u8),
+
+    /// S pin is driven low
+    Low,
+}
+
+impl From<u8> for SPinControl {
+    fn from(value: u8) -> Self {
+        match value & 0x0F {
+            0x0 => SPinControl::High,
+            0x1..=0x7 => SPinControl::Pulses(value & 0x0F),
+            _ => SPinControl::Low,
+        }
+    }
+}
+
+/// Error in case of an invalid S pin control setting
+#[derive(Debug, PartialEq, Eq)]
+pub enum SControlError {
+    /// Channel is not a cell or the cell has no S control setting
+    InvalidCell,
+
+    /// Number of pulses is not within 1-7
+    OutOfRange,
+}
+
+impl Display for SControlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SControlError::InvalidCell => write!(f, "Channel has no S control setting"),
+            SControlError::OutOfRange => write!(f, "Number of pulses not within 1-7"),
+        }
+    }
+}
+
+/// S pin control of external balancing circuits (S control register group)
+///
+/// S pins are only controlled by this register, if discharge of the corresponding cell is turned off
+/// in the configuration. Pulsing is started by the STSCTRL command.
+pub struct SControlConfiguration<T: DeviceTypes> {
+    /// Computed value of S control register (byte 0-5) + S control part of PSB register (byte 6-8)
+    pub(crate) register: [u8; 9],
+
+    device_types: PhantomData<T>,
+}
+
+impl<T: DeviceTypes> Default for SControlConfiguration<T> {
+    fn default() -> Self {
+        Self {
+            register: [0x0; 9],
+            device_types: PhantomData,
+        }
+    }
+}
+
+impl<T: DeviceTypes> SControlConfiguration<T> {
+    /// Sets the S pin output of the given cell
+    pub fn set_control(&mut self, cell: T::Channel, control: SPinControl) -> Result<(), SControlError> {
+        let (index, shift) = locate_cell_setting::<T>(cell).ok_or(SControlError::InvalidCell)?;
+
+        let value = match control {
+            SPinControl::High => 0x0,
+            SPinControl::Pulses(count @ 1..=7) => count,
+            SPinControl::Pulses(_) => return Err(SControlError::OutOfRange),
+            SPinControl::Low => 0b1000,
+        };
+
+        self.register[index] &= !(0x0F << shift);
+        self.register[index] |= value << shift;
+
+        Ok(())
+    }
+
+    /// Returns the S pin output of the given cell. None if the channel has no S control setting.
+    pub fn control(&self, cell: T::Channel) -> Option<SPinControl> {
+        let (index, shift) = locate_cell_setting::<T>(cell)?;
+        Some(SPinControl::from(self.register[index] >> shift))
+    }
+}
+
+impl<T: DeviceTypes> Clone for SControlConfiguration<T> {
+    fn clone(&self) -> Self {
+        Self {
+            register: self.register,
+            device_types: PhantomData,
+        }
+    }
+}
+
+impl<T: DeviceTypes> PartialEq<Self> for SControlConfiguration<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.register == other.register
+    }
+}
+
+impl<T: DeviceTypes> Eq for SControlConfiguration<T> {}
+
+impl<T: DeviceTypes> Debug for SControlConfiguration<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SControlConfiguration")
+            .field("register", &self.register)
+            .finish()
+    }
+}
+
+impl<T: DeviceTypes> Configuration<T> {
     /// Enables pull-down of the given GPIO pin
-    pub fn enable_gpio_pull_down(&mut self, pin: GPIO) {
-        match pin {
+    pub fn enable_gpio_pull_down(&mut self, pin: T::GPIO) {
+        match pin.into() {
             GPIO::GPIO1 => self.register_a[0] &= 0b1111_0111,
             GPIO::GPIO2 => self.register_a[0] &= 0b1110_1111,
             GPIO::GPIO3 => self.register_a[0] &= 0b1101_1111,
@@ -196,8 +559,8 @@ impl Configuration {
     }
 
     /// Enables pull-down of the given GPIO pin
-    pub fn disable_gpio_pull_down(&mut self, pin: GPIO) {
-        match pin {
+    pub fn disable_gpio_pull_down(&mut self, pin: T::GPIO) {
+        match pin.into() {
             GPIO::GPIO1 => self.register_a[0] |= 0b0000_1000,
             GPIO::GPIO2 => self.register_a[0] |= 0b0001_0000,
             GPIO::GPIO3 => self.register_a[0] |= 0b0010_0000,
@@ -273,8 +636,8 @@ impl Configuration {
     }
 
     /// Turn ON Shorting Switch for Cell x
-    pub fn discharge_cell(&mut self, cell: Cell) {
-        match cell {
+    pub fn discharge_cell(&mut self, cell: T::Cell) {
+        match cell.into() {
             Cell::Cell1 => self.register_a[4] |= 0b0000_0001,
             Cell::Cell2 => self.register_a[4] |= 0b0000_0010,
             Cell::Cell3 => self.register_a[4] |= 0b0000_0100,
@@ -312,6 +675,85 @@ impl Configuration {
         self.register_a[0] &= 0b1111_1110
     }
 
+    /// Returns true if the pull-down of the given GPIO pin is enabled
+    pub fn gpio_pull_down_enabled(&self, pin: T::GPIO) -> bool {
+        match pin.into() {
+            GPIO::GPIO1 => self.register_a[0] & 0b0000_1000 == 0,
+            GPIO::GPIO2 => self.register_a[0] & 0b0001_0000 == 0,
+            GPIO::GPIO3 => self.register_a[0] & 0b0010_0000 == 0,
+            GPIO::GPIO4 => self.register_a[0] & 0b0100_0000 == 0,
+            GPIO::GPIO5 => self.register_a[0] & 0b1000_0000 == 0,
+            GPIO::GPIO6 => self.register_b[0] & 0b0000_0001 == 0,
+            GPIO::GPIO7 => self.register_b[0] & 0b0000_0010 == 0,
+            GPIO::GPIO8 => self.register_b[0] & 0b0000_0100 == 0,
+            GPIO::GPIO9 => self.register_b[0] & 0b0000_1000 == 0,
+        }
+    }
+
+    /// Returns true if the references remain powered up until watchdog timeout (REFON)
+    pub fn reference_power_enabled(&self) -> bool {
+        self.register_a[0] & 0b0000_0100 != 0
+    }
+
+    /// Returns true if the discharge timer is enabled (DTEN)
+    pub fn discharge_timer_enabled(&self) -> bool {
+        self.register_a[0] & 0b0000_0010 != 0
+    }
+
+    /// Returns true if the alternative ADC modes are selected (ADCOPT)
+    pub fn alternative_adc_modes(&self) -> bool {
+        self.register_a[0] & 0b0000_0001 != 0
+    }
+
+    /// Returns the under-voltage comparison voltage in uV, 0 if not set
+    pub fn uv_comp_voltage(&self) -> u32 {
+        let value = self.register_a[1] as u32 | ((self.register_a[2] & 0b0000_1111) as u32) << 8;
+
+        if value == 0 {
+            return 0;
+        }
+
+        (value + 1) * 1600
+    }
+
+    /// Returns the over-voltage comparison voltage in uV
+    pub fn ov_comp_voltage(&self) -> u32 {
+        let value = (self.register_a[2] >> 4) as u32 | (self.register_a[3] as u32) << 4;
+
+        value * 1600
+    }
+
+    /// Returns true if the shorting switch of the given cell is turned on
+    pub fn cell_discharged(&self, cell: T::Cell) -> bool {
+        match cell.into() {
+            Cell::Cell1 => self.register_a[4] & 0b0000_0001 != 0,
+            Cell::Cell2 => self.register_a[4] & 0b0000_0010 != 0,
+            Cell::Cell3 => self.register_a[4] & 0b0000_0100 != 0,
+            Cell::Cell4 => self.register_a[4] & 0b0000_1000 != 0,
+            Cell::Cell5 => self.register_a[4] & 0b0001_0000 != 0,
+            Cell::Cell6 => self.register_a[4] & 0b0010_0000 != 0,
+            Cell::Cell7 => self.register_a[4] & 0b0100_0000 != 0,
+            Cell::Cell8 => self.register_a[4] & 0b1000_0000 != 0,
+            Cell::Cell9 => self.register_a[5] & 0b0000_0001 != 0,
+            Cell::Cell10 => self.register_a[5] & 0b0000_0010 != 0,
+            Cell::Cell11 => self.register_a[5] & 0b0000_0100 != 0,
+            Cell::Cell12 => self.register_a[5] & 0b0000_1000 != 0,
+            Cell::Cell13 => self.register_b[0] & 0b0001_0000 != 0,
+            Cell::Cell14 => self.register_b[0] & 0b0010_0000 != 0,
+            Cell::Cell15 => self.register_b[0] & 0b0100_0000 != 0,
+            Cell::Cell16 => self.register_b[0] & 0b1000_0000 != 0,
+            Cell::Cell17 => self.register_b[1] & 0b0000_0001 != 0,
+            Cell::Cell18 => self.register_b[1] & 0b0000_0010 != 0,
+        }
+    }
+
+    /// Returns the discharge timeout (DCTO)
+    pub fn discharge_timeout(&self) -> DischargeTimeout {
+        DischargeTimeout::from(self.register_a[5] >> 4)
+    }
+}
+
+impl<T: ConfigurationRegisterB> Configuration<T> {
     /// Forces the digital redundancy comparison for ADC Conversions to fail
     pub fn force_digital_redundancy_fail(&mut self) {
         self.register_b[1] |= 0b0100_0000;
@@ -331,13 +773,100 @@ impl Configuration {
     }
 }
 
-impl PartialEq<Self> for Configuration {
+/// LTC6810 specific settings. The configuration register of LTC6810 locates these bits in register A.
+impl Configuration<LTC6810> {
+    /// Turn ON discharge of the S0 pin (DCC0)
+    pub fn discharge_s0(&mut self) {
+        self.register_a[4] |= 0b1000_0000;
+    }
+
+    /// Returns true if the discharge of the S0 pin is turned on (DCC0)
+    pub fn s0_discharged(&self) -> bool {
+        self.register_a[4] & 0b1000_0000 != 0
+    }
+
+    /// Enables the multi-calibration of the ADC, running a calibration on each conversion (MCAL)
+    pub fn enable_multi_calibration(&mut self) {
+        self.register_a[4] |= 0b0100_0000;
+    }
+
+    /// Disables the multi-calibration, calibration is only run after the device left the STANDBY state (Default)
+    pub fn disable_multi_calibration(&mut self) {
+        self.register_a[4] &= 0b1011_1111;
+    }
+
+    /// Returns true if the multi-calibration is enabled (MCAL)
+    pub fn multi_calibration_enabled(&self) -> bool {
+        self.register_a[4] & 0b0100_0000 != 0
+    }
+
+    /// Cell conversions measure the S pins redundantly instead of the C pins (SCONV)
+    pub fn enable_redundant_s_pin_conversion(&mut self) {
+        self.register_a[5] |= 0b0000_1000;
+    }
+
+    /// Cell conversions measure the C pins (Default)
+    pub fn disable_redundant_s_pin_conversion(&mut self) {
+        self.register_a[5] &= 0b1111_0111;
+    }
+
+    /// Returns true if the cell conversions measure the S pins (SCONV)
+    pub fn redundant_s_pin_conversion_enabled(&self) -> bool {
+        self.register_a[5] & 0b0000_1000 != 0
+    }
+
+    /// Forces the digital redundancy comparison for ADC Conversions to fail (FDRF)
+    pub fn force_digital_redundancy_fail(&mut self) {
+        self.register_a[5] |= 0b0000_0100;
+    }
+
+    /// Returns true if the digital redundancy comparison is forced to fail (FDRF)
+    pub fn digital_redundancy_fail_forced(&self) -> bool {
+        self.register_a[5] & 0b0000_0100 != 0
+    }
+
+    /// Enables the discharge timer monitor function if the DTEN Pin is Asserted (DTMEN)
+    pub fn enable_discharge_monitor(&mut self) {
+        self.register_a[5] |= 0b0000_0001;
+    }
+
+    /// Disables the discharge timer monitor function (Default)
+    pub fn disable_discharge_monitor(&mut self) {
+        self.register_a[5] &= 0b1111_1110;
+    }
+
+    /// Returns true if the discharge timer monitor function is enabled (DTMEN)
+    pub fn discharge_monitor_enabled(&self) -> bool {
+        self.register_a[5] & 0b0000_0001 != 0
+    }
+}
+
+impl<T: DeviceTypes> Clone for Configuration<T> {
+    fn clone(&self) -> Self {
+        Self {
+            register_a: self.register_a,
+            register_b: self.register_b,
+            device_types: PhantomData,
+        }
+    }
+}
+
+impl<T: DeviceTypes> PartialEq<Self> for Configuration<T> {
     fn eq(&self, other: &Self) -> bool {
         self.register_a == other.register_a && self.register_b == other.register_b
     }
 }
 
-impl Eq for Configuration {}
+impl<T: DeviceTypes> Eq for Configuration<T> {}
+
+impl<T: DeviceTypes> Debug for Configuration<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Configuration")
+            .field("register_a", &self.register_a)
+            .field("register_b", &self.register_b)
+            .finish()
+    }
+}
 
 impl From<usize> for Cell {
     fn from(index: usize) -> Self {
@@ -365,6 +894,29 @@ impl From<usize> for Cell {
     }
 }
 
+impl From<u8> for DischargeTimeout {
+    fn from(value: u8) -> Self {
+        match value & 0x0F {
+            0x1 => DischargeTimeout::HalfMinute,
+            0x2 => DischargeTimeout::OneMinute,
+            0x3 => DischargeTimeout::TwoMinutes,
+            0x4 => DischargeTimeout::ThreeMinutes,
+            0x5 => DischargeTimeout::FourMinutes,
+            0x6 => DischargeTimeout::FiveMinutes,
+            0x7 => DischargeTimeout::TenMinutes,
+            0x8 => DischargeTimeout::FifteenMinutes,
+            0x9 => DischargeTimeout::TwentyMinutes,
+            0xA => DischargeTimeout::ThirtyMinutes,
+            0xB => DischargeTimeout::FortyMinutes,
+            0xC => DischargeTimeout::SixtyMinutes,
+            0xD => DischargeTimeout::SeventyFiveMinutes,
+            0xE => DischargeTimeout::NinetyMinutes,
+            0xF => DischargeTimeout::TwoHours,
+            _ => DischargeTimeout::Disabled,
+        }
+    }
+}
+
 impl From<usize> for GPIO {
     fn from(index: usize) -> Self {
         match index {
@@ -0,0 +1,217 @@
+//! # Runtime-sized daisy chains
+//!
+//! The chain length of [LTC681X] is the const generic `L`, so supporting several pack sizes requires one client
+//! per size. [DynamicLTC681X] instead chooses the chain length at construction time, bounded by the compile-time
+//! maximum `MAX` of the underlying client. SPI transactions just cover the actual number of devices, while buffers
+//! are sized by the maximum.
+//!
+//! Results are returned as [heapless::Vec], holding one item per connected device.
+//! Data arguments are passed as slices, which must hold one item per connected device.
+//!
+//!````
+//! use ltc681x::config::Configuration;
+//! use ltc681x::dynamic::DynamicLTC681X;
+//! use ltc681x::example::ExampleSPIDevice;
+//! use ltc681x::ltc6813::{CellSelection, Channel, LTC6813};
+//! use ltc681x::monitor::{ADCMode, LTC681X, LTC681XClient};
+//!
+//! // Up to eight devices, two are actually connected
+//! let client: LTC681X<_, _, LTC6813, 8> = LTC681X::ltc6813(ExampleSPIDevice::default());
+//! let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+//!
+//! chain.write_configuration(&[Configuration::default(), Configuration::default()]).unwrap();
+//!
+//! // Commands without device specific results are issued by the underlying client
+//! chain.client().start_conv_cells(ADCMode::Normal, CellSelection::All, false).unwrap();
+//!
+//! let voltages = chain.read_voltages(CellSelection::Group1).unwrap();
+//! assert_eq!(2, voltages.len());
+//! assert_eq!(Channel::Cell1, voltages[1][0].channel);
+//! assert_eq!(24979, voltages[1][0].voltage);
+//!````
+//!
+//! All other commands are available via the [underlying client](DynamicLTC681X::client). Its vector results hold one
+//! item per connected device as well, while its array results are sized by `MAX`, holding default values (zeros) for
+//! positions without a connected device.
+use crate::config::{Configuration, PWMConfiguration, SControlConfiguration};
+use crate::monitor::{
+    CheckedDeviceParameters, CheckedVoltage, DeviceTypes, Error, InternalDeviceParameters, LTC681XClient, PollClient,
+    PollMethod, RegisterLocator, StatusB, Voltage, LTC681X,
+};
+use embedded_hal::spi::SpiDevice;
+use heapless::Vec;
+
+/// Client for daisy chains with a chain length chosen at runtime
+pub struct DynamicLTC681X<B, P, T, const MAX: usize>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+{
+    /// Client issuing the commands, sized by the maximum chain length
+    client: LTC681X<B, P, T, MAX>,
+}
+
+impl<B, P, T, const MAX: usize> DynamicLTC681X<B, P, T, MAX>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+{
+    /// Creates a new client for the given number of devices in daisy chain
+    ///
+    /// # Arguments
+    ///
+    /// * `client`: Client sized by the maximum chain length, which may use any poll method
+    /// * `length`: Number of devices actually connected in daisy chain
+    ///
+    /// Returns [Error::InvalidChainLength] in case the length is zero or exceeds the maximum chain length
+    pub fn new(mut client: LTC681X<B, P, T, MAX>, length: usize) -> Result<Self, Error<B>> {
+        if length == 0 || length > MAX {
+            return Err(Error::InvalidChainLength(length));
+        }
+
+        client.set_chain_length(length);
+        Ok(Self { client })
+    }
+
+    /// Returns the number of devices actually connected in daisy chain
+    pub fn length(&self) -> usize {
+        self.client.chain_length()
+    }
+
+    /// Returns the underlying client, e.g. for issuing commands without device specific results
+    pub fn client(&mut self) -> &mut LTC681X<B, P, T, MAX> {
+        &mut self.client
+    }
+
+    /// Reads the values of the given register, one item per device in daisy chain
+    pub fn read_register(&mut self, register: T::Register) -> Result<Vec<[u16; 3], MAX>, Error<B>> {
+        let result = self.client.read_register(register)?;
+        Ok(self.truncate(result))
+    }
+
+    /// Writes the values of the given register, one slice item per device in daisy chain
+    ///
+    /// Returns [Error::LengthMismatch] in case the number of items does not match the chain length
+    pub fn write_register(&mut self, register: T::Register, data: &[[u8; 6]]) -> Result<(), Error<B>> {
+        let data = self.expand(data)?;
+        self.client.write_register(register, data)
+    }
+
+    /// Writes the configuration, one slice item per device in daisy chain
+    ///
+    /// Returns [Error::LengthMismatch] in case the number of items does not match the chain length
+    pub fn write_configuration(&mut self, config: &[Configuration<T>]) -> Result<(), Error<B>> {
+        let config = self.expand(config)?;
+        self.client.write_configuration(config)
+    }
+
+    /// Writes the configuration and verifies it by reading it back, one slice item per device in daisy chain
+    ///
+    /// See [LTC681XClient::write_configuration_verified](LTC681XClient#tymethod.write_configuration_verified)
+    ///
+    /// Returns [Error::LengthMismatch] in case the number of items does not match the chain length
+    pub fn write_configuration_verified(
+        &mut self,
+        config: &[Configuration<T>],
+        retries: usize,
+    ) -> Result<(), Error<B>> {
+        let config = self.expand(config)?;
+        self.client.write_configuration_verified(config, retries)
+    }
+
+    /// Reads the configuration registers, one item per device in daisy chain
+    pub fn read_configuration(&mut self) -> Result<Vec<Configuration<T>, MAX>, Error<B>> {
+        let result = self.client.read_configuration()?;
+        Ok(self.truncate(result))
+    }
+
+    /// Writes the PWM duty cycles of cell discharge, one slice item per device in daisy chain
+    ///
+    /// Returns [Error::LengthMismatch] in case the number of items does not match the chain length
+    pub fn write_pwm(&mut self, config: &[PWMConfiguration<T>]) -> Result<(), Error<B>> {
+        let config = self.expand(config)?;
+        self.client.write_pwm(config)
+    }
+
+    /// Reads the PWM duty cycles of cell discharge, one item per device in daisy chain
+    pub fn read_pwm(&mut self) -> Result<Vec<PWMConfiguration<T>, MAX>, Error<B>> {
+        let result = self.client.read_pwm()?;
+        Ok(self.truncate(result))
+    }
+
+    /// Writes the S pin control, one slice item per device in daisy chain
+    ///
+    /// Returns [Error::LengthMismatch] in case the number of items does not match the chain length
+    pub fn write_s_control(&mut self, config: &[SControlConfiguration<T>]) -> Result<(), Error<B>> {
+        let config = self.expand(config)?;
+        self.client.write_s_control(config)
+    }
+
+    /// Reads the S pin control, one item per device in daisy chain
+    pub fn read_s_control(&mut self) -> Result<Vec<SControlConfiguration<T>, MAX>, Error<B>> {
+        let result = self.client.read_s_control()?;
+        Ok(self.truncate(result))
+    }
+
+    /// Reads and returns the conversion result (voltages) of Cell or GPIO group, one item per device in daisy chain
+    pub fn read_voltages<R: RegisterLocator<T> + 'static>(
+        &mut self,
+        locator: R,
+    ) -> Result<Vec<Vec<Voltage<T>, 18>, MAX>, Error<B>> {
+        self.client.read_voltages(locator)
+    }
+
+    /// Reads the voltages of Cell or GPIO group and detects fault codes, one item per device in daisy chain
+    pub fn read_checked_voltages<R: RegisterLocator<T> + 'static>(
+        &mut self,
+        locator: R,
+    ) -> Result<Vec<Vec<CheckedVoltage<T>, 18>, MAX>, Error<B>> {
+        self.client.read_checked_voltages(locator)
+    }
+
+    /// Reads internal device parameters measured by ADSTAT command, one item per device in daisy chain
+    pub fn read_internal_device_parameters(&mut self) -> Result<Vec<InternalDeviceParameters, MAX>, Error<B>> {
+        self.client.read_internal_device_parameters()
+    }
+
+    /// Reads internal device parameters and detects fault codes, one item per device in daisy chain
+    pub fn read_checked_internal_device_parameters(&mut self) -> Result<Vec<CheckedDeviceParameters, MAX>, Error<B>> {
+        self.client.read_checked_internal_device_parameters()
+    }
+
+    /// Reads and decodes status register group B, one item per device in daisy chain
+    pub fn read_status_b(&mut self) -> Result<Vec<StatusB<T>, MAX>, Error<B>> {
+        self.client.read_status_b()
+    }
+
+    /// Returns the items of the connected devices
+    fn truncate<V, I: IntoIterator<Item = V>>(&self, items: I) -> Vec<V, MAX> {
+        items.into_iter().take(self.length()).collect()
+    }
+
+    /// Expands the given items of the connected devices to the maximum chain length
+    fn expand<V: Clone + Default>(&self, items: &[V]) -> Result<[V; MAX], Error<B>> {
+        if items.len() != self.length() {
+            return Err(Error::LengthMismatch(items.len()));
+        }
+
+        Ok(core::array::from_fn(|i| items.get(i).cloned().unwrap_or_default()))
+    }
+}
+
+impl<B, P, T, const MAX: usize> PollClient for DynamicLTC681X<B, P, T, MAX>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+    LTC681X<B, P, T, MAX>: PollClient,
+{
+    type Error = <LTC681X<B, P, T, MAX> as PollClient>::Error;
+
+    /// See [PollClient::adc_ready]
+    fn adc_ready(&mut self) -> Result<bool, Self::Error> {
+        self.client.adc_ready()
+    }
+}
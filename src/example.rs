@@ -1,5 +1,6 @@
 //! SPI bus mock for doc examples
 use core::convert::Infallible;
+use embedded_hal::delay::DelayNs;
 use embedded_hal::digital::OutputPin;
 use embedded_hal::spi::{ErrorType, Operation, SpiBus, SpiDevice};
 
@@ -20,7 +21,8 @@ impl SpiDevice<u8> for ExampleSPIDevice {
                     Self::response(command, buffer);
                 }
                 Operation::Transfer(buffer, write) => {
-                    command = write[1];
+                    // Address bits of address commands are ignored
+                    command = (((write[0] & 0b0000_0111) as u16) << 8) | write[1] as u16;
                     Self::response(command, &mut buffer[4..]);
                 }
                 Operation::TransferInPlace(_) => panic!("Unexpected TransferInPlace operation"),
@@ -34,24 +36,48 @@ impl SpiDevice<u8> for ExampleSPIDevice {
 }
 
 impl ExampleSPIDevice {
-    fn response(command: u8, buffer: &mut [u8]) {
+    fn response(command: u16, buffer: &mut [u8]) {
         match command {
+            // PLADC command, conversion finished
+            0b0111_0001_0100 => buffer.fill(0xFF),
+            // Configuration register A
+            0b0000_0010 => buffer.copy_from_slice(&[0xFC, 0x52, 0x17, 0xA4, 0x04, 0x20, 0x0D, 0x9A]),
+            // Configuration register B
+            0b0010_0110 => buffer.copy_from_slice(&[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68]),
+            // COMM register, byte 0x2A acknowledged by I2C slave
+            0b0111_0010_0010 => buffer.copy_from_slice(&[0x62, 0xA7, 0x02, 0xA7, 0x02, 0xA7, 0x70, 0xF6]),
+            // PWM register, duty cycle of cell 1 set to 8
+            0b0010_0010 => buffer.copy_from_slice(&[0xF8, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xD3, 0xC4]),
+            // S control register, 3 pulses on S1 and S2 driven low
+            0b0001_0110 => buffer.copy_from_slice(&[0x83, 0x00, 0x00, 0x00, 0x00, 0x00, 0x35, 0x12]),
+            // PWM/S control register B, 100 % duty cycle of cells 13-18
+            0b0001_1110 => buffer.copy_from_slice(&[0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x8D, 0x1C]),
+            // Serial ID register (LTC6810)
+            0b0010_1100 => buffer.copy_from_slice(&[0x78, 0x56, 0x34, 0x12, 0xCD, 0xAB, 0xB2, 0x9A]),
             // Status register A
             0b0001_0000 => buffer.copy_from_slice(&[0x12, 0x62, 0xA8, 0x62, 0x00, 0x7D, 0x31, 0x8A]),
             // Status register B
-            0b0001_0010 => buffer.copy_from_slice(&[0x00, 0xC8, 0x00, 0x66, 0x00, 0x1B, 0xF1, 0x40]),
+            0b0001_0010 => buffer.copy_from_slice(&[0x00, 0xC8, 0x00, 0x66, 0x00, 0x10, 0x0F, 0x2A]),
             // Cell voltage register B
             0b0000_0100 => buffer.copy_from_slice(&[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C]),
             // Cell voltage register B
             0b0000_0110 => buffer.copy_from_slice(&[0xDD, 0x66, 0x72, 0x1D, 0xA2, 0x1C, 0x11, 0x94]),
             // Cell voltage register C
             0b0000_1000 => buffer.copy_from_slice(&[0x61, 0x63, 0xBD, 0x1E, 0xE4, 0x22, 0x3F, 0x42]),
+            // Cell voltage register D
+            0b0000_1010 => buffer.copy_from_slice(&[0xB4, 0x61, 0xDD, 0x1E, 0x6C, 0x22, 0x76, 0xF4]),
             // Cell voltage register E
             0b0000_1001 => buffer.copy_from_slice(&[0xDE, 0x64, 0x8F, 0x21, 0x8A, 0x21, 0x8F, 0xDA]),
+            // Cell voltage register F
+            0b0000_1011 => buffer.copy_from_slice(&[0x2A, 0x62, 0x10, 0x1F, 0x25, 0x22, 0x74, 0x96]),
             // Aux voltage register A
             0b0000_1100 => buffer.copy_from_slice(&[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C]),
+            // Aux voltage register B
+            0b0000_1110 => buffer.copy_from_slice(&[0x58, 0x1D, 0xB8, 0x1D, 0x34, 0x75, 0xCD, 0x68]),
             // Aux voltage register C
             0b0000_1101 => buffer.copy_from_slice(&[0x61, 0x63, 0xBD, 0x1E, 0xE4, 0x22, 0x3F, 0x42]),
+            // Aux voltage register D
+            0b0000_1111 => buffer.copy_from_slice(&[0x20, 0x1E, 0xFF, 0xFF, 0x00, 0x00, 0x3F, 0x2E]),
             _ => buffer.copy_from_slice(&[0x0; 8]),
         };
     }
@@ -113,3 +139,10 @@ impl OutputPin for ExampleCSPin {
         Ok(())
     }
 }
+
+/// Delay mock for doc examples
+pub struct ExampleDelay {}
+
+impl DelayNs for ExampleDelay {
+    fn delay_ns(&mut self, _ns: u32) {}
+}
@@ -1,16 +1,36 @@
 //! # Generic client for LTC681X battery stack monitors
 //!
-//! Supports all devices of LTC681X family: [LTC6813](crate::ltc6813::LTC6813), [LTC6812](crate::ltc6812::LTC6812), [LTC6811](crate::ltc6811::LTC6811) and [LTC6810](crate::ltc6810::LTC6810).
+//! Supports all devices of LTC681X family: [LTC6813](crate::ltc6813::LTC6813), [LTC6812](crate::ltc6812::LTC6812), [LTC6811](crate::ltc6811::LTC6811) and [LTC6810](crate::ltc6810::LTC6810), as well as the LTC6813 successor [ADBMS1818](crate::adbms1818::ADBMS1818) and the LTC6811 predecessor [LTC6804](crate::ltc6804::LTC6804).
 //!
 //! Currently the following features are implemented:
+//! * [Wake-up from SLEEP and IDLE state](crate::monitor#wake-up)
 //! * [Cell and GPIO conversion](crate::monitor#conversion)
+//! * [Combined cell and GPIO or sum of cells conversion](crate::monitor#combined-conversion-adcvax-and-adcvsc-commands)
 //! * [Reading cell and GPIO voltage registers](crate::monitor#reading-registers)
 //! * [Multiple devices in daisy chain](crate::monitor#multiple-devices-in-daisy-chain)
-//! * [ADC status polling (SDO line method)](crate::monitor#polling)
+//! * [Daisy chains mixing different device types](crate::chain)
+//! * [Daisy chain length chosen at runtime](crate::dynamic)
+//! * [Addressable "-2" device variants](crate::monitor#addressable-devices)
+//! * [ADC status polling (SDO line or PLADC command method)](crate::monitor#polling)
 //! * [Mapping voltages to GPIO and cell groups](crate::monitor#mapping-voltages)
 //! * [Abstracted device configuration](crate::config)
+//! * [Reading back the device configuration](crate::config#reading-the-configuration)
+//! * [Verified configuration writing](crate::config#verified-writing)
+//! * [Device-specific configuration](crate::config#device-specific-settings)
+//! * [PWM controlled cell discharge](crate::config#pwm-discharge)
+//! * [S pin control of external balancing circuits](crate::config#s-pin-control)
 //! * [Overlapping ADC measurement](crate::monitor#overlap-measurement-adol-command)
 //! * [Internal device parameters measurement](crate::monitor#internal-device-parameters-adstat-command)
+//! * [Digital redundancy of GPIO and status conversions](crate::monitor#digital-redundancy-adaxd-and-adstatd-commands)
+//! * [Clearing result registers](crate::monitor#clearing-registers-clrcell-clraux-and-clrstat-commands)
+//! * [Muting discharge during measurements](crate::monitor#muting-discharge-mute-and-unmute-commands)
+//! * [Digital filter self-tests](crate::monitor#digital-filter-self-tests-cvst-axst-and-statst-commands)
+//! * [Decoded status register B](crate::monitor#status-register-group-b)
+//! * [Multiplexer diagnostic](crate::monitor#multiplexer-diagnostic-diagn-command)
+//! * [Open-wire detection](crate::monitor#open-wire-detection-adow-command)
+//! * [Serial ID readout of LTC6810](crate::monitor::LTC681X::read_serial_ids)
+//! * [I2C master via GPIO pins (COMM register)](crate::comm#i2c)
+//! * [SPI master via GPIO pins (COMM register)](crate::comm#spi)
 //!
 //! # Example
 //!
@@ -45,9 +65,14 @@ extern crate alloc;
 
 pub use heapless;
 
+pub mod adbms1818;
+pub mod chain;
+pub mod comm;
 pub mod config;
+pub mod dynamic;
 #[cfg(feature = "example")]
 pub mod example;
+pub mod ltc6804;
 pub mod ltc6810;
 pub mod ltc6811;
 pub mod ltc6812;
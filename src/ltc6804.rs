@@ -0,0 +1,404 @@
+//! Device-specific types for [LTC6804](<https://www.analog.com/en/products/ltc6804-1.html>)
+use crate::commands::{
+    CMD_R_AUX_V_REG_A, CMD_R_AUX_V_REG_B, CMD_R_CELL_V_REG_A, CMD_R_CELL_V_REG_B, CMD_R_CELL_V_REG_C,
+    CMD_R_CELL_V_REG_D, CMD_R_COMM, CMD_R_CONF_A, CMD_R_STATUS_A, CMD_R_STATUS_B, CMD_W_COMM, CMD_W_CONF_A,
+};
+use crate::config;
+use crate::ltc6811::{self, LTC6811};
+use crate::monitor::{
+    ADCMode, ChannelIndex, ChannelType, CommandTime, ConversionTiming, DeviceTypes, GroupedRegisterIndex, NoPolling,
+    NoWriteCommandError, RegisterAddress, RegisterLocator, ToCommandBitmap, ToCommandTiming, ToFullCommand, LTC681X,
+};
+use core::slice::Iter;
+use embedded_hal::spi::SpiDevice;
+
+/// Cell selection for ADC conversion
+///
+/// The conversion times in table 5 of the [datasheet](<https://www.analog.com/media/en/technical-documentation/data-sheets/680412fc.pdf>)
+/// are identical to the LTC6811 ones, so the LTC6811 timing tables are shared.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum CellSelection {
+    /// All cells
+    All = 0x0,
+    /// Cells 1 and 7
+    Pair1 = 0x1,
+    /// Cells 2 and 8
+    Pair2 = 0x2,
+    /// Cells 3 and 9
+    Pair3 = 0x3,
+    /// Cells 4 and 10
+    Pair4 = 0x4,
+    /// Cells 5 and 11
+    Pair5 = 0x5,
+    /// Cells 6 and 12
+    Pair6 = 0x6,
+}
+
+/// GPIO selection for ADC conversion,
+///
+/// The conversion times of the [datasheet](<https://www.analog.com/media/en/technical-documentation/data-sheets/680412fc.pdf>)
+/// are identical to the LTC6811 ones, so the LTC6811 timing tables are shared.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GPIOSelection {
+    /// GPIO 1-5 and 2nd Reference
+    All = 0x0,
+    GPIO1 = 0x1,
+    GPIO2 = 0x2,
+    GPIO3 = 0x3,
+    GPIO4 = 0x4,
+    GPIO5 = 0x5,
+    SecondReference = 0x6,
+}
+
+/// Available registers
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Register {
+    CellVoltageA,
+    CellVoltageB,
+    CellVoltageC,
+    CellVoltageD,
+    AuxiliaryA,
+    AuxiliaryB,
+    StatusA,
+    StatusB,
+    Configuration,
+    Comm,
+}
+
+/// Cells of the device, e.g. used for configuring the discharge
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Cell {
+    Cell1 = 0,
+    Cell2 = 1,
+    Cell3 = 2,
+    Cell4 = 3,
+    Cell5 = 4,
+    Cell6 = 5,
+    Cell7 = 6,
+    Cell8 = 7,
+    Cell9 = 8,
+    Cell10 = 9,
+    Cell11 = 10,
+    Cell12 = 11,
+}
+
+/// GPIO pins of the device, e.g. used for configuring the pull-down
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GPIO {
+    GPIO1 = 0,
+    GPIO2 = 1,
+    GPIO3 = 2,
+    GPIO4 = 3,
+    GPIO5 = 4,
+}
+
+/// All conversion channels
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Channel {
+    Cell1,
+    Cell2,
+    Cell3,
+    Cell4,
+    Cell5,
+    Cell6,
+    Cell7,
+    Cell8,
+    Cell9,
+    Cell10,
+    Cell11,
+    Cell12,
+    GPIO1,
+    GPIO2,
+    GPIO3,
+    GPIO4,
+    GPIO5,
+    SecondReference,
+}
+
+/// Device type of LTC6804
+///
+/// Predecessor of LTC6811 sharing its command protocol, but without a second configuration register,
+/// without PWM and S control register groups and without ADCVSC, MUTE and UNMUTE commands.
+pub struct LTC6804 {}
+
+impl DeviceTypes for LTC6804 {
+    type CellSelection = CellSelection;
+    type GPIOSelection = GPIOSelection;
+    type Register = Register;
+    type Channel = Channel;
+    type Cell = Cell;
+    type GPIO = GPIO;
+
+    const CELL_COUNT: usize = 12;
+    const GPIO_COUNT: usize = 5;
+
+    const ALL_CELLS: Self::CellSelection = CellSelection::All;
+    const ALL_GPIOS: Self::GPIOSelection = GPIOSelection::All;
+
+    const WAKE_UP_TIME: u32 = 300;
+    const READY_TIME: u32 = 10;
+
+    // ADCVAX conversion times of the datasheet are identical to the LTC6811 ones
+    const CELL_GPIO_CONV_TIMING: ConversionTiming = LTC6811::CELL_GPIO_CONV_TIMING;
+
+    // ADCVSC, MUTE and UNMUTE commands are not available on LTC6804
+    const CELL_SUM_CONV_TIMING: Option<ConversionTiming> = None;
+    const DISCHARGE_MUTE: bool = false;
+
+    const OVERLAP_TEST_REG_1: Option<Self::Register> = None;
+    const OVERLAP_TEST_REG_2: Option<Self::Register> = None;
+
+    const REG_STATUS_A: Self::Register = Register::StatusA;
+    const REG_STATUS_B: Self::Register = Register::StatusB;
+    const REG_AUX_D: Option<Self::Register> = None;
+
+    const REG_CONF_A: Self::Register = Register::Configuration;
+    const REG_CONF_B: Option<Self::Register> = None;
+    const REG_CONF_A_MASK: [u8; 6] = [0b0000_0101, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    const REG_CONF_B_MASK: [u8; 6] = [0x00; 6];
+
+    const REG_COMM: Self::Register = Register::Comm;
+    const REG_PWM: Option<Self::Register> = None;
+    const REG_S_CONTROL: Option<Self::Register> = None;
+    const REG_PSB: Option<Self::Register> = None;
+
+    const TOTAL_VOLTAGE_FACTOR: u32 = 20;
+    // Internal die temperature = ITMP * 100 uV / (7.5 mV/°C) - 273 °C, see section "Measuring Internal Device
+    // Parameters" of datasheet
+    const INTERNAL_TEMP_GAIN: i32 = 7500;
+    const INTERNAL_TEMP_OFFSET: i16 = 273;
+}
+
+impl<B, const L: usize> LTC681X<B, NoPolling, LTC6804, L>
+where
+    B: SpiDevice<u8>,
+{
+    /// Creates a client instant for LTC6804 variant
+    pub fn ltc6804(bus: B) -> Self {
+        LTC681X::new(bus)
+    }
+}
+
+impl ToCommandBitmap for CellSelection {
+    fn to_bitmap(&self) -> u16 {
+        *self as u16
+    }
+}
+
+impl ToCommandBitmap for GPIOSelection {
+    fn to_bitmap(&self) -> u16 {
+        *self as u16
+    }
+}
+
+impl ToFullCommand for Register {
+    /// Returns the precalculated full command
+    fn to_read_command(&self) -> [u8; 4] {
+        match self {
+            Register::CellVoltageA => CMD_R_CELL_V_REG_A,
+            Register::CellVoltageB => CMD_R_CELL_V_REG_B,
+            Register::CellVoltageC => CMD_R_CELL_V_REG_C,
+            Register::CellVoltageD => CMD_R_CELL_V_REG_D,
+            Register::AuxiliaryA => CMD_R_AUX_V_REG_A,
+            Register::AuxiliaryB => CMD_R_AUX_V_REG_B,
+            Register::StatusA => CMD_R_STATUS_A,
+            Register::StatusB => CMD_R_STATUS_B,
+            Register::Configuration => CMD_R_CONF_A,
+            Register::Comm => CMD_R_COMM,
+        }
+    }
+
+    fn to_write_command(&self) -> Result<[u8; 4], NoWriteCommandError> {
+        match self {
+            Register::Configuration => Ok(CMD_W_CONF_A),
+            Register::Comm => Ok(CMD_W_COMM),
+            _ => Err(NoWriteCommandError {}),
+        }
+    }
+}
+
+impl ToCommandTiming for CellSelection {
+    fn to_conv_command_timing(&self, mode: ADCMode) -> CommandTime {
+        let timing = match self {
+            CellSelection::All => ltc6811::ALL_CHANNELS_CONV_TIMING,
+            CellSelection::Pair1
+            | CellSelection::Pair2
+            | CellSelection::Pair3
+            | CellSelection::Pair4
+            | CellSelection::Pair5
+            | CellSelection::Pair6 => ltc6811::CELL_PAIR_CONV_TIMING,
+        };
+
+        timing.to_conv_command_timing(mode)
+    }
+}
+
+impl ToCommandTiming for GPIOSelection {
+    fn to_conv_command_timing(&self, mode: ADCMode) -> CommandTime {
+        let timing = match self {
+            GPIOSelection::All => ltc6811::ALL_CHANNELS_CONV_TIMING,
+            GPIOSelection::GPIO1
+            | GPIOSelection::GPIO2
+            | GPIOSelection::GPIO3
+            | GPIOSelection::GPIO4
+            | GPIOSelection::GPIO5
+            | GPIOSelection::SecondReference => ltc6811::SINGLE_GPIO_CONV_TIMING,
+        };
+
+        timing.to_conv_command_timing(mode)
+    }
+}
+
+impl GroupedRegisterIndex for Register {
+    fn to_index(&self) -> usize {
+        match self {
+            Register::CellVoltageA => 0,
+            Register::CellVoltageB => 1,
+            Register::CellVoltageC => 2,
+            Register::CellVoltageD => 3,
+            Register::AuxiliaryA => 0,
+            Register::AuxiliaryB => 1,
+            Register::StatusA => 0,
+            Register::StatusB => 1,
+            Register::Configuration => 0,
+            Register::Comm => 0,
+        }
+    }
+}
+
+impl ChannelIndex for Channel {
+    fn to_cell_index(&self) -> Option<usize> {
+        match self {
+            Channel::Cell1 => Some(0),
+            Channel::Cell2 => Some(1),
+            Channel::Cell3 => Some(2),
+            Channel::Cell4 => Some(3),
+            Channel::Cell5 => Some(4),
+            Channel::Cell6 => Some(5),
+            Channel::Cell7 => Some(6),
+            Channel::Cell8 => Some(7),
+            Channel::Cell9 => Some(8),
+            Channel::Cell10 => Some(9),
+            Channel::Cell11 => Some(10),
+            Channel::Cell12 => Some(11),
+            _ => None,
+        }
+    }
+
+    fn to_gpio_index(&self) -> Option<usize> {
+        match self {
+            Channel::GPIO1 => Some(0),
+            Channel::GPIO2 => Some(1),
+            Channel::GPIO3 => Some(2),
+            Channel::GPIO4 => Some(3),
+            Channel::GPIO5 => Some(4),
+            _ => None,
+        }
+    }
+}
+
+impl From<Cell> for config::Cell {
+    fn from(cell: Cell) -> Self {
+        config::Cell::from(cell as usize)
+    }
+}
+
+impl From<GPIO> for config::GPIO {
+    fn from(pin: GPIO) -> Self {
+        config::GPIO::from(pin as usize)
+    }
+}
+
+impl From<Channel> for ChannelType {
+    fn from(channel: Channel) -> Self {
+        match channel {
+            Channel::GPIO1 => ChannelType::GPIO,
+            Channel::GPIO2 => ChannelType::GPIO,
+            Channel::GPIO3 => ChannelType::GPIO,
+            Channel::GPIO4 => ChannelType::GPIO,
+            Channel::GPIO5 => ChannelType::GPIO,
+            Channel::SecondReference => ChannelType::Reference,
+            _ => ChannelType::Cell,
+        }
+    }
+}
+
+impl RegisterAddress<LTC6804> {
+    pub const fn ltc6804(channel: Channel, register: Register, slot: usize) -> Self {
+        RegisterAddress {
+            channel,
+            register,
+            slot,
+        }
+    }
+}
+
+/// Cell register locations ordered by Channel.
+const CELL_REGISTER_LOCATIONS: [RegisterAddress<LTC6804>; 12] = [
+    RegisterAddress::ltc6804(Channel::Cell1, Register::CellVoltageA, 0),
+    RegisterAddress::ltc6804(Channel::Cell2, Register::CellVoltageA, 1),
+    RegisterAddress::ltc6804(Channel::Cell3, Register::CellVoltageA, 2),
+    RegisterAddress::ltc6804(Channel::Cell4, Register::CellVoltageB, 0),
+    RegisterAddress::ltc6804(Channel::Cell5, Register::CellVoltageB, 1),
+    RegisterAddress::ltc6804(Channel::Cell6, Register::CellVoltageB, 2),
+    RegisterAddress::ltc6804(Channel::Cell7, Register::CellVoltageC, 0),
+    RegisterAddress::ltc6804(Channel::Cell8, Register::CellVoltageC, 1),
+    RegisterAddress::ltc6804(Channel::Cell9, Register::CellVoltageC, 2),
+    RegisterAddress::ltc6804(Channel::Cell10, Register::CellVoltageD, 0),
+    RegisterAddress::ltc6804(Channel::Cell11, Register::CellVoltageD, 1),
+    RegisterAddress::ltc6804(Channel::Cell12, Register::CellVoltageD, 2),
+];
+
+/// Cell register locations ordered by CellSelection. These definitions
+/// should match CELL_REGISTER_LOCATIONS.
+const CELL_REGISTER_LOCATIONS_BY_PAIRS: [RegisterAddress<LTC6804>; 12] = [
+    RegisterAddress::ltc6804(Channel::Cell1, Register::CellVoltageA, 0),
+    RegisterAddress::ltc6804(Channel::Cell7, Register::CellVoltageC, 0),
+    RegisterAddress::ltc6804(Channel::Cell2, Register::CellVoltageA, 1),
+    RegisterAddress::ltc6804(Channel::Cell8, Register::CellVoltageC, 1),
+    RegisterAddress::ltc6804(Channel::Cell3, Register::CellVoltageA, 2),
+    RegisterAddress::ltc6804(Channel::Cell9, Register::CellVoltageC, 2),
+    RegisterAddress::ltc6804(Channel::Cell4, Register::CellVoltageB, 0),
+    RegisterAddress::ltc6804(Channel::Cell10, Register::CellVoltageD, 0),
+    RegisterAddress::ltc6804(Channel::Cell5, Register::CellVoltageB, 1),
+    RegisterAddress::ltc6804(Channel::Cell11, Register::CellVoltageD, 1),
+    RegisterAddress::ltc6804(Channel::Cell6, Register::CellVoltageB, 2),
+    RegisterAddress::ltc6804(Channel::Cell12, Register::CellVoltageD, 2),
+];
+
+impl RegisterLocator<LTC6804> for CellSelection {
+    fn get_locations(&self) -> Iter<'static, RegisterAddress<LTC6804>> {
+        match self {
+            CellSelection::All => CELL_REGISTER_LOCATIONS.iter(),
+            CellSelection::Pair1 => CELL_REGISTER_LOCATIONS_BY_PAIRS[0..2].iter(),
+            CellSelection::Pair2 => CELL_REGISTER_LOCATIONS_BY_PAIRS[2..4].iter(),
+            CellSelection::Pair3 => CELL_REGISTER_LOCATIONS_BY_PAIRS[4..6].iter(),
+            CellSelection::Pair4 => CELL_REGISTER_LOCATIONS_BY_PAIRS[6..8].iter(),
+            CellSelection::Pair5 => CELL_REGISTER_LOCATIONS_BY_PAIRS[8..10].iter(),
+            CellSelection::Pair6 => CELL_REGISTER_LOCATIONS_BY_PAIRS[10..12].iter(),
+        }
+    }
+}
+
+const GPIO_REGISTER_LOCATIONS: [RegisterAddress<LTC6804>; 6] = [
+    RegisterAddress::ltc6804(Channel::GPIO1, Register::AuxiliaryA, 0),
+    RegisterAddress::ltc6804(Channel::GPIO2, Register::AuxiliaryA, 1),
+    RegisterAddress::ltc6804(Channel::GPIO3, Register::AuxiliaryA, 2),
+    RegisterAddress::ltc6804(Channel::GPIO4, Register::AuxiliaryB, 0),
+    RegisterAddress::ltc6804(Channel::GPIO5, Register::AuxiliaryB, 1),
+    RegisterAddress::ltc6804(Channel::SecondReference, Register::AuxiliaryB, 2),
+];
+
+impl RegisterLocator<LTC6804> for GPIOSelection {
+    fn get_locations(&self) -> Iter<'static, RegisterAddress<LTC6804>> {
+        match self {
+            GPIOSelection::All => GPIO_REGISTER_LOCATIONS.iter(),
+            GPIOSelection::GPIO1 => GPIO_REGISTER_LOCATIONS[0..1].iter(),
+            GPIOSelection::GPIO2 => GPIO_REGISTER_LOCATIONS[1..2].iter(),
+            GPIOSelection::GPIO3 => GPIO_REGISTER_LOCATIONS[2..3].iter(),
+            GPIOSelection::GPIO4 => GPIO_REGISTER_LOCATIONS[3..4].iter(),
+            GPIOSelection::GPIO5 => GPIO_REGISTER_LOCATIONS[4..5].iter(),
+            GPIOSelection::SecondReference => GPIO_REGISTER_LOCATIONS[5..6].iter(),
+        }
+    }
+}
@@ -1,11 +1,13 @@
 //! Device-specific types for [LTC6810](<https://www.analog.com/en/products/ltc6810-1.html>)
 use crate::commands::{
-    CMD_R_AUX_V_REG_A, CMD_R_AUX_V_REG_B, CMD_R_CELL_V_REG_A, CMD_R_CELL_V_REG_B, CMD_R_CONF_A, CMD_R_STATUS_A,
-    CMD_R_STATUS_B, CMD_W_CONF_A,
+    CMD_R_AUX_V_REG_A, CMD_R_AUX_V_REG_B, CMD_R_CELL_V_REG_A, CMD_R_CELL_V_REG_B, CMD_R_COMM, CMD_R_CONF_A, CMD_R_PWM,
+    CMD_R_SCTRL, CMD_R_SID, CMD_R_STATUS_A, CMD_R_STATUS_B, CMD_W_COMM, CMD_W_CONF_A, CMD_W_PWM, CMD_W_SCTRL,
 };
+use crate::config;
 use crate::monitor::{
-    ADCMode, ChannelIndex, ChannelType, CommandTime, DeviceTypes, GroupedRegisterIndex, NoPolling, NoWriteCommandError,
-    RegisterAddress, RegisterLocator, ToCommandBitmap, ToCommandTiming, ToFullCommand, LTC681X,
+    ADCMode, ChannelIndex, ChannelType, CommandTime, ConversionTiming, DeviceTypes, Error, GroupedRegisterIndex,
+    LTC681XClient, NoPolling, NoWriteCommandError, PollMethod, RegisterAddress, RegisterLocator, ToCommandBitmap,
+    ToCommandTiming, ToFullCommand, LTC681X,
 };
 use core::slice::Iter;
 use embedded_hal::spi::SpiDevice;
@@ -52,6 +54,30 @@ pub enum Register {
     StatusA,
     StatusB,
     Configuration,
+    Comm,
+    PWM,
+    SControl,
+    SerialID,
+}
+
+/// Cells of the device, e.g. used for configuring the discharge
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Cell {
+    Cell1 = 0,
+    Cell2 = 1,
+    Cell3 = 2,
+    Cell4 = 3,
+    Cell5 = 4,
+    Cell6 = 5,
+}
+
+/// GPIO pins of the device, e.g. used for configuring the pull-down
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GPIO {
+    GPIO1 = 0,
+    GPIO2 = 1,
+    GPIO3 = 2,
+    GPIO4 = 3,
 }
 
 /// All conversion channels
@@ -79,18 +105,50 @@ impl DeviceTypes for LTC6810 {
     type GPIOSelection = GPIOSelection;
     type Register = Register;
     type Channel = Channel;
+    type Cell = Cell;
+    type GPIO = GPIO;
 
     const CELL_COUNT: usize = 6;
     const GPIO_COUNT: usize = 4;
 
+    const ALL_CELLS: Self::CellSelection = CellSelection::All;
+    const ALL_GPIOS: Self::GPIOSelection = GPIOSelection::All;
+
+    const WAKE_UP_TIME: u32 = 400;
+    const READY_TIME: u32 = 10;
+
+    const CELL_GPIO_CONV_TIMING: ConversionTiming = ConversionTiming {
+        fast: CommandTime::new(1506, 1739),
+        normal: CommandTime::new(3136, 4066),
+        filtered: CommandTime::new(268_444, 5929),
+        other: CommandTime::new(17_099, 9651),
+    };
+
+    const CELL_SUM_CONV_TIMING: Option<ConversionTiming> = Some(ConversionTiming {
+        fast: CommandTime::new(1306, 1510),
+        normal: CommandTime::new(2732, 3546),
+        filtered: CommandTime::new(234_877, 5176),
+        other: CommandTime::new(14_950, 8433),
+    });
+
+    const DISCHARGE_MUTE: bool = true;
+
     const OVERLAP_TEST_REG_1: Option<Self::Register> = None;
     const OVERLAP_TEST_REG_2: Option<Self::Register> = None;
 
     const REG_STATUS_A: Self::Register = Register::StatusA;
     const REG_STATUS_B: Self::Register = Register::StatusB;
+    const REG_AUX_D: Option<Self::Register> = None;
 
     const REG_CONF_A: Self::Register = Register::Configuration;
     const REG_CONF_B: Option<Self::Register> = None;
+    const REG_CONF_A_MASK: [u8; 6] = [0b0000_0101, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    const REG_CONF_B_MASK: [u8; 6] = [0x00; 6];
+
+    const REG_COMM: Self::Register = Register::Comm;
+    const REG_PWM: Option<Self::Register> = Some(Register::PWM);
+    const REG_S_CONTROL: Option<Self::Register> = Some(Register::SControl);
+    const REG_PSB: Option<Self::Register> = None;
 
     const TOTAL_VOLTAGE_FACTOR: u32 = 10;
     const INTERNAL_TEMP_GAIN: i32 = 7600;
@@ -107,6 +165,38 @@ where
     }
 }
 
+impl<B, P, const L: usize> LTC681X<B, P, LTC6810, L>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+{
+    /// Reads the unique 48-bit serial ID (RDSID command)
+    /// Returns one ID for each device in daisy chain
+    ///
+    /// ````
+    /// use ltc681x::example::ExampleSPIDevice;
+    /// use ltc681x::ltc6810::LTC6810;
+    /// use ltc681x::monitor::LTC681X;
+    ///
+    /// let mut client: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(ExampleSPIDevice::default());
+    ///
+    /// let ids = client.read_serial_ids().unwrap();
+    /// assert_eq!(0xABCD_1234_5678, ids[0]);
+    /// ````
+    pub fn read_serial_ids(&mut self) -> Result<[u64; L], Error<B>> {
+        let register = self.read_register(Register::SerialID)?;
+
+        let mut result = [0u64; L];
+        for (device_index, id) in result.iter_mut().take(self.chain_length()).enumerate() {
+            for (slot, value) in register[device_index].iter().enumerate() {
+                *id |= (*value as u64) << (slot * 16);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
 impl ToCommandBitmap for CellSelection {
     fn to_bitmap(&self) -> u16 {
         *self as u16
@@ -130,12 +220,19 @@ impl ToFullCommand for Register {
             Register::StatusA => CMD_R_STATUS_A,
             Register::StatusB => CMD_R_STATUS_B,
             Register::Configuration => CMD_R_CONF_A,
+            Register::Comm => CMD_R_COMM,
+            Register::PWM => CMD_R_PWM,
+            Register::SControl => CMD_R_SCTRL,
+            Register::SerialID => CMD_R_SID,
         }
     }
 
     fn to_write_command(&self) -> Result<[u8; 4], NoWriteCommandError> {
         match self {
             Register::Configuration => Ok(CMD_W_CONF_A),
+            Register::Comm => Ok(CMD_W_COMM),
+            Register::PWM => Ok(CMD_W_PWM),
+            Register::SControl => Ok(CMD_W_SCTRL),
             _ => Err(NoWriteCommandError {}),
         }
     }
@@ -199,6 +296,10 @@ impl GroupedRegisterIndex for Register {
             Register::StatusA => 0,
             Register::StatusB => 1,
             Register::Configuration => 0,
+            Register::Comm => 0,
+            Register::PWM => 0,
+            Register::SControl => 0,
+            Register::SerialID => 0,
         }
     }
 }
@@ -227,6 +328,18 @@ impl ChannelIndex for Channel {
     }
 }
 
+impl From<Cell> for config::Cell {
+    fn from(cell: Cell) -> Self {
+        config::Cell::from(cell as usize)
+    }
+}
+
+impl From<GPIO> for config::GPIO {
+    fn from(pin: GPIO) -> Self {
+        config::GPIO::from(pin as usize)
+    }
+}
+
 impl From<Channel> for ChannelType {
     fn from(channel: Channel) -> Self {
         match channel {
@@ -1,11 +1,13 @@
 //! Device-specific types for [LTC6811](<https://www.analog.com/en/products/ltc6811-1.html>)
 use crate::commands::{
     CMD_R_AUX_V_REG_A, CMD_R_AUX_V_REG_B, CMD_R_CELL_V_REG_A, CMD_R_CELL_V_REG_B, CMD_R_CELL_V_REG_C,
-    CMD_R_CELL_V_REG_D, CMD_R_CONF_A, CMD_R_CONF_B, CMD_R_STATUS_A, CMD_R_STATUS_B, CMD_W_CONF_A, CMD_W_CONF_B,
+    CMD_R_CELL_V_REG_D, CMD_R_COMM, CMD_R_CONF_A, CMD_R_PWM, CMD_R_SCTRL, CMD_R_STATUS_A, CMD_R_STATUS_B, CMD_W_COMM,
+    CMD_W_CONF_A, CMD_W_PWM, CMD_W_SCTRL,
 };
+use crate::config;
 use crate::monitor::{
-    ADCMode, ChannelIndex, ChannelType, CommandTime, DeviceTypes, GroupedRegisterIndex, NoPolling, NoWriteCommandError,
-    RegisterAddress, RegisterLocator, ToCommandBitmap, ToCommandTiming, ToFullCommand, LTC681X,
+    ADCMode, ChannelIndex, ChannelType, CommandTime, ConversionTiming, DeviceTypes, GroupedRegisterIndex, NoPolling,
+    NoWriteCommandError, RegisterAddress, RegisterLocator, ToCommandBitmap, ToCommandTiming, ToFullCommand, LTC681X,
 };
 use core::slice::Iter;
 use embedded_hal::spi::SpiDevice;
@@ -60,7 +62,36 @@ pub enum Register {
     StatusA,
     StatusB,
     ConfigurationA,
-    ConfigurationB,
+    Comm,
+    PWM,
+    SControl,
+}
+
+/// Cells of the device, e.g. used for configuring the discharge
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Cell {
+    Cell1 = 0,
+    Cell2 = 1,
+    Cell3 = 2,
+    Cell4 = 3,
+    Cell5 = 4,
+    Cell6 = 5,
+    Cell7 = 6,
+    Cell8 = 7,
+    Cell9 = 8,
+    Cell10 = 9,
+    Cell11 = 10,
+    Cell12 = 11,
+}
+
+/// GPIO pins of the device, e.g. used for configuring the pull-down
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GPIO {
+    GPIO1 = 0,
+    GPIO2 = 1,
+    GPIO3 = 2,
+    GPIO4 = 3,
+    GPIO5 = 4,
 }
 
 /// All conversion channels
@@ -94,18 +125,50 @@ impl DeviceTypes for LTC6811 {
     type GPIOSelection = GPIOSelection;
     type Register = Register;
     type Channel = Channel;
+    type Cell = Cell;
+    type GPIO = GPIO;
 
     const CELL_COUNT: usize = 12;
     const GPIO_COUNT: usize = 5;
 
+    const ALL_CELLS: Self::CellSelection = CellSelection::All;
+    const ALL_GPIOS: Self::GPIOSelection = GPIOSelection::All;
+
+    const WAKE_UP_TIME: u32 = 400;
+    const READY_TIME: u32 = 10;
+
+    const CELL_GPIO_CONV_TIMING: ConversionTiming = ConversionTiming {
+        fast: CommandTime::new(1515, 1748),
+        normal: CommandTime::new(3145, 4035),
+        filtered: CommandTime::new(268_453, 5938),
+        other: CommandTime::new(17_107, 9660),
+    };
+
+    const CELL_SUM_CONV_TIMING: Option<ConversionTiming> = Some(ConversionTiming {
+        fast: CommandTime::new(1314, 1518),
+        normal: CommandTime::new(2740, 3534),
+        filtered: CommandTime::new(234_885, 5184),
+        other: CommandTime::new(14_957, 8441),
+    });
+
+    const DISCHARGE_MUTE: bool = true;
+
     const OVERLAP_TEST_REG_1: Option<Self::Register> = Some(Register::CellVoltageC);
     const OVERLAP_TEST_REG_2: Option<Self::Register> = None;
 
     const REG_STATUS_A: Self::Register = Register::StatusA;
     const REG_STATUS_B: Self::Register = Register::StatusB;
+    const REG_AUX_D: Option<Self::Register> = None;
 
     const REG_CONF_A: Self::Register = Register::ConfigurationA;
-    const REG_CONF_B: Option<Self::Register> = Some(Register::ConfigurationB);
+    const REG_CONF_B: Option<Self::Register> = None;
+    const REG_CONF_A_MASK: [u8; 6] = [0b0000_0101, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    const REG_CONF_B_MASK: [u8; 6] = [0x00; 6];
+
+    const REG_COMM: Self::Register = Register::Comm;
+    const REG_PWM: Option<Self::Register> = Some(Register::PWM);
+    const REG_S_CONTROL: Option<Self::Register> = Some(Register::SControl);
+    const REG_PSB: Option<Self::Register> = None;
 
     const TOTAL_VOLTAGE_FACTOR: u32 = 20;
     const INTERNAL_TEMP_GAIN: i32 = 7500;
@@ -147,64 +210,76 @@ impl ToFullCommand for Register {
             Register::StatusA => CMD_R_STATUS_A,
             Register::StatusB => CMD_R_STATUS_B,
             Register::ConfigurationA => CMD_R_CONF_A,
-            Register::ConfigurationB => CMD_R_CONF_B,
+            Register::Comm => CMD_R_COMM,
+            Register::PWM => CMD_R_PWM,
+            Register::SControl => CMD_R_SCTRL,
         }
     }
 
     fn to_write_command(&self) -> Result<[u8; 4], NoWriteCommandError> {
         match self {
             Register::ConfigurationA => Ok(CMD_W_CONF_A),
-            Register::ConfigurationB => Ok(CMD_W_CONF_B),
+            Register::Comm => Ok(CMD_W_COMM),
+            Register::PWM => Ok(CMD_W_PWM),
+            Register::SControl => Ok(CMD_W_SCTRL),
             _ => Err(NoWriteCommandError {}),
         }
     }
 }
 
+/// Conversion times of all cells (ADCV command) or all GPIOs (ADAX command)
+pub(crate) const ALL_CHANNELS_CONV_TIMING: ConversionTiming = ConversionTiming {
+    fast: CommandTime::new(1113, 1288),
+    normal: CommandTime::new(2335, 3033),
+    filtered: CommandTime::new(201_317, 4430),
+    other: CommandTime::new(12_807, 7222),
+};
+
+/// Conversion times of a single cell pair (ADCV command)
+pub(crate) const CELL_PAIR_CONV_TIMING: ConversionTiming = ConversionTiming {
+    fast: CommandTime::new(201, 230),
+    normal: CommandTime::new(405, 501),
+    filtered: CommandTime::new(33_568, 754),
+    other: CommandTime::new(2150, 1219),
+};
+
+/// Conversion times of a single GPIO or the second reference (ADAX command)
+pub(crate) const SINGLE_GPIO_CONV_TIMING: ConversionTiming = ConversionTiming {
+    fast: CommandTime::new(201, 230),
+    normal: CommandTime::new(405, 501),
+    filtered: CommandTime::new(34_000, 751),
+    other: CommandTime::new(2_200, 1_200),
+};
+
 impl ToCommandTiming for CellSelection {
     fn to_conv_command_timing(&self, mode: ADCMode) -> CommandTime {
-        match self {
-            CellSelection::All => match mode {
-                ADCMode::Fast => CommandTime::new(1113, 1288),
-                ADCMode::Normal => CommandTime::new(2335, 3033),
-                ADCMode::Filtered => CommandTime::new(201_317, 4430),
-                ADCMode::Other => CommandTime::new(12_807, 7222),
-            },
+        let timing = match self {
+            CellSelection::All => ALL_CHANNELS_CONV_TIMING,
             CellSelection::Pair1
             | CellSelection::Pair2
             | CellSelection::Pair3
             | CellSelection::Pair4
             | CellSelection::Pair5
-            | CellSelection::Pair6 => match mode {
-                ADCMode::Fast => CommandTime::new(201, 230),
-                ADCMode::Normal => CommandTime::new(405, 501),
-                ADCMode::Filtered => CommandTime::new(33_568, 754),
-                ADCMode::Other => CommandTime::new(2150, 1219),
-            },
-        }
+            | CellSelection::Pair6 => CELL_PAIR_CONV_TIMING,
+        };
+
+        timing.to_conv_command_timing(mode)
     }
 }
 
 impl ToCommandTiming for GPIOSelection {
     fn to_conv_command_timing(&self, mode: ADCMode) -> CommandTime {
-        match self {
-            GPIOSelection::All => match mode {
-                ADCMode::Fast => CommandTime::new(1113, 1288),
-                ADCMode::Normal => CommandTime::new(2335, 3033),
-                ADCMode::Filtered => CommandTime::new(201_317, 4430),
-                ADCMode::Other => CommandTime::new(12_807, 7_222),
-            },
+        let timing = match self {
+            GPIOSelection::All => ALL_CHANNELS_CONV_TIMING,
             GPIOSelection::GPIO1
             | GPIOSelection::GPIO2
             | GPIOSelection::GPIO3
             | GPIOSelection::GPIO4
             | GPIOSelection::GPIO5
-            | GPIOSelection::SecondReference => match mode {
-                ADCMode::Fast => CommandTime::new(201, 230),
-                ADCMode::Normal => CommandTime::new(405, 501),
-                ADCMode::Filtered => CommandTime::new(34_000, 751),
-                ADCMode::Other => CommandTime::new(2_200, 1_200),
-            },
-        }
+            | GPIOSelection::SecondReference => SINGLE_GPIO_CONV_TIMING,
+        };
+
+        timing.to_conv_command_timing(mode)
     }
 }
 
@@ -220,7 +295,9 @@ impl GroupedRegisterIndex for Register {
             Register::StatusA => 0,
             Register::StatusB => 1,
             Register::ConfigurationA => 0,
-            Register::ConfigurationB => 1,
+            Register::Comm => 0,
+            Register::PWM => 0,
+            Register::SControl => 0,
         }
     }
 }
@@ -256,6 +333,18 @@ impl ChannelIndex for Channel {
     }
 }
 
+impl From<Cell> for config::Cell {
+    fn from(cell: Cell) -> Self {
+        config::Cell::from(cell as usize)
+    }
+}
+
+impl From<GPIO> for config::GPIO {
+    fn from(pin: GPIO) -> Self {
+        config::GPIO::from(pin as usize)
+    }
+}
+
 impl From<Channel> for ChannelType {
     fn from(channel: Channel) -> Self {
         match channel {
@@ -1,12 +1,14 @@
 //! Device-specific types for [LTC6812](<https://www.analog.com/en/products/ltc6812-1.html>)
 use crate::commands::{
     CMD_R_AUX_V_REG_A, CMD_R_AUX_V_REG_B, CMD_R_AUX_V_REG_C, CMD_R_AUX_V_REG_D, CMD_R_CELL_V_REG_A, CMD_R_CELL_V_REG_B,
-    CMD_R_CELL_V_REG_C, CMD_R_CELL_V_REG_D, CMD_R_CELL_V_REG_E, CMD_R_CONF_A, CMD_R_CONF_B, CMD_R_STATUS_A,
-    CMD_R_STATUS_B, CMD_W_CONF_A, CMD_W_CONF_B,
+    CMD_R_CELL_V_REG_C, CMD_R_CELL_V_REG_D, CMD_R_CELL_V_REG_E, CMD_R_COMM, CMD_R_CONF_A, CMD_R_CONF_B, CMD_R_PSB,
+    CMD_R_PWM, CMD_R_SCTRL, CMD_R_STATUS_A, CMD_R_STATUS_B, CMD_W_COMM, CMD_W_CONF_A, CMD_W_CONF_B, CMD_W_PSB,
+    CMD_W_PWM, CMD_W_SCTRL,
 };
+use crate::config::{self, ConfigurationRegisterB};
 use crate::monitor::{
-    ADCMode, ChannelIndex, ChannelType, CommandTime, DeviceTypes, GroupedRegisterIndex, NoPolling, NoWriteCommandError,
-    RegisterAddress, RegisterLocator, ToCommandBitmap, ToCommandTiming, ToFullCommand, LTC681X,
+    ADCMode, ChannelIndex, ChannelType, CommandTime, ConversionTiming, DeviceTypes, GroupedRegisterIndex, NoPolling,
+    NoWriteCommandError, RegisterAddress, RegisterLocator, ToCommandBitmap, ToCommandTiming, ToFullCommand, LTC681X,
 };
 use core::slice::Iter;
 use embedded_hal::spi::SpiDevice;
@@ -69,6 +71,44 @@ pub enum Register {
     StatusB,
     ConfigurationA,
     ConfigurationB,
+    Comm,
+    PWM,
+    SControl,
+    PWMSControlB,
+}
+
+/// Cells of the device, e.g. used for configuring the discharge
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Cell {
+    Cell1 = 0,
+    Cell2 = 1,
+    Cell3 = 2,
+    Cell4 = 3,
+    Cell5 = 4,
+    Cell6 = 5,
+    Cell7 = 6,
+    Cell8 = 7,
+    Cell9 = 8,
+    Cell10 = 9,
+    Cell11 = 10,
+    Cell12 = 11,
+    Cell13 = 12,
+    Cell14 = 13,
+    Cell15 = 14,
+}
+
+/// GPIO pins of the device, e.g. used for configuring the pull-down
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GPIO {
+    GPIO1 = 0,
+    GPIO2 = 1,
+    GPIO3 = 2,
+    GPIO4 = 3,
+    GPIO5 = 4,
+    GPIO6 = 5,
+    GPIO7 = 6,
+    GPIO8 = 7,
+    GPIO9 = 8,
 }
 
 /// All conversion channels
@@ -109,18 +149,50 @@ impl DeviceTypes for LTC6812 {
     type GPIOSelection = GPIOSelection;
     type Register = Register;
     type Channel = Channel;
+    type Cell = Cell;
+    type GPIO = GPIO;
 
     const CELL_COUNT: usize = 15;
     const GPIO_COUNT: usize = 9;
 
+    const ALL_CELLS: Self::CellSelection = CellSelection::All;
+    const ALL_GPIOS: Self::GPIOSelection = GPIOSelection::All;
+
+    const WAKE_UP_TIME: u32 = 400;
+    const READY_TIME: u32 = 10;
+
+    const CELL_GPIO_CONV_TIMING: ConversionTiming = ConversionTiming {
+        fast: CommandTime::new(1343, 1547),
+        normal: CommandTime::new(2770, 3583),
+        filtered: CommandTime::new(234_914, 5213),
+        other: CommandTime::new(14_987, 8470),
+    };
+
+    const CELL_SUM_CONV_TIMING: Option<ConversionTiming> = Some(ConversionTiming {
+        fast: CommandTime::new(1140, 1315),
+        normal: CommandTime::new(2363, 3060),
+        filtered: CommandTime::new(201_344, 4457),
+        other: CommandTime::new(12_835, 7249),
+    });
+
+    const DISCHARGE_MUTE: bool = true;
+
     const OVERLAP_TEST_REG_1: Option<Self::Register> = Some(Register::CellVoltageC);
     const OVERLAP_TEST_REG_2: Option<Self::Register> = Some(Register::CellVoltageE);
 
     const REG_STATUS_A: Self::Register = Register::StatusA;
     const REG_STATUS_B: Self::Register = Register::StatusB;
+    const REG_AUX_D: Option<Self::Register> = Some(Register::AuxiliaryD);
 
     const REG_CONF_A: Self::Register = Register::ConfigurationA;
     const REG_CONF_B: Option<Self::Register> = Some(Register::ConfigurationB);
+    const REG_CONF_A_MASK: [u8; 6] = [0b0000_0101, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    const REG_CONF_B_MASK: [u8; 6] = [0b0111_0000, 0b0111_1100, 0x00, 0x00, 0x00, 0x00];
+
+    const REG_COMM: Self::Register = Register::Comm;
+    const REG_PWM: Option<Self::Register> = Some(Register::PWM);
+    const REG_S_CONTROL: Option<Self::Register> = Some(Register::SControl);
+    const REG_PSB: Option<Self::Register> = Some(Register::PWMSControlB);
 
     const TOTAL_VOLTAGE_FACTOR: u32 = 30;
     const INTERNAL_TEMP_GAIN: i32 = 7600;
@@ -166,6 +238,10 @@ impl ToFullCommand for Register {
             Register::StatusB => CMD_R_STATUS_B,
             Register::ConfigurationA => CMD_R_CONF_A,
             Register::ConfigurationB => CMD_R_CONF_B,
+            Register::Comm => CMD_R_COMM,
+            Register::PWM => CMD_R_PWM,
+            Register::SControl => CMD_R_SCTRL,
+            Register::PWMSControlB => CMD_R_PSB,
         }
     }
 
@@ -173,6 +249,10 @@ impl ToFullCommand for Register {
         match self {
             Register::ConfigurationA => Ok(CMD_W_CONF_A),
             Register::ConfigurationB => Ok(CMD_W_CONF_B),
+            Register::Comm => Ok(CMD_W_COMM),
+            Register::PWM => Ok(CMD_W_PWM),
+            Register::SControl => Ok(CMD_W_SCTRL),
+            Register::PWMSControlB => Ok(CMD_W_PSB),
             _ => Err(NoWriteCommandError {}),
         }
     }
@@ -243,6 +323,10 @@ impl GroupedRegisterIndex for Register {
             Register::StatusB => 1,
             Register::ConfigurationA => 0,
             Register::ConfigurationB => 1,
+            Register::Comm => 0,
+            Register::PWM => 0,
+            Register::SControl => 0,
+            Register::PWMSControlB => 1,
         }
     }
 }
@@ -285,6 +369,20 @@ impl ChannelIndex for Channel {
     }
 }
 
+impl From<Cell> for config::Cell {
+    fn from(cell: Cell) -> Self {
+        config::Cell::from(cell as usize)
+    }
+}
+
+impl From<GPIO> for config::GPIO {
+    fn from(pin: GPIO) -> Self {
+        config::GPIO::from(pin as usize)
+    }
+}
+
+impl ConfigurationRegisterB for LTC6812 {}
+
 impl From<Channel> for ChannelType {
     fn from(channel: Channel) -> Self {
         match channel {
@@ -1,3 +1,4 @@
+use embedded_hal::delay::DelayNs;
 use embedded_hal::digital::{ErrorType, OutputPin};
 use embedded_hal::spi::{Error, ErrorKind, Operation, SpiBus, SpiDevice};
 use mockall::mock;
@@ -59,6 +60,14 @@ impl embedded_hal::digital::Error for PinError {
     }
 }
 
+mock! {
+    pub Delay {}
+
+    impl DelayNs for Delay {
+        fn delay_ns(&mut self, ns: u32);
+    }
+}
+
 pub struct DeviceMockBuilder {
     device: MockSPIDevice,
 }
@@ -135,6 +144,47 @@ impl DeviceMockBuilder {
         self
     }
 
+    pub fn expect_wake_up_pulse(mut self) -> Self {
+        self.device.expect_transaction().times(1).returning(move |operation| {
+            assert_eq!(1, operation.len());
+
+            match operation[0] {
+                Operation::Write(data) => assert_eq!(&[0xFF], data),
+                _ => panic!("Received unexpected operation type {:?}", operation[0]),
+            }
+
+            Ok(())
+        });
+
+        self
+    }
+
+    pub fn expect_pladc_poll(mut self, response: u8) -> Self {
+        self.device.expect_transaction().times(1).returning(move |operations| {
+            assert_eq!(1, operations.len());
+
+            match &mut operations[0] {
+                Operation::Transfer(buffer, command) => {
+                    assert_eq!(5, buffer.len());
+                    assert_eq!(&[0x07, 0x14, 0xF3, 0x6C, 0xFF], command);
+
+                    buffer[4] = response;
+                }
+                _ => panic!("Received unexpected operation type {:?}", operations[0]),
+            }
+
+            Ok(())
+        });
+
+        self
+    }
+
+    pub fn expect_start_comm(self) -> Self {
+        self.expect_register_write(&[&[
+            0x07, 0x23, 0xB9, 0xE4, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        ]])
+    }
+
     pub fn expect_register_write<const N: usize>(mut self, expected: &'static [&'static [u8]; N]) -> Self {
         self.device.expect_transaction().times(1).returning(move |operation| {
             assert_eq!(N, operation.len());
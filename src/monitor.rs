@@ -6,7 +6,7 @@
 //!
 //! The [client](LTC681X) is based on a SPI bus, which implements the [embedded-hal SPI Transfer trait](<https://docs.rs/embedded-hal/latest/embedded_hal/blocking/spi/trait.Transfer.html>)
 //! and contains the following two generic parameters:
-//! * T: Device specific types ([DeviceTypes] trait). See [LTC6813](crate::ltc6813::LTC6813), [LTC6812](crate::ltc6812::LTC6812), [LTC6811](crate::ltc6811::LTC6811) and [LTC6810](crate::ltc6810::LTC6810)
+//! * T: Device specific types ([DeviceTypes] trait). See [ADBMS1818](crate::adbms1818::ADBMS1818), [LTC6813](crate::ltc6813::LTC6813), [LTC6812](crate::ltc6812::LTC6812), [LTC6811](crate::ltc6811::LTC6811), [LTC6810](crate::ltc6810::LTC6810) and [LTC6804](crate::ltc6804::LTC6804)
 //! * L: Number of devices in daisy chain
 //!
 //! ````
@@ -24,6 +24,46 @@
 //! let client: LTC681X<_, _, LTC6812, 3> = LTC681X::ltc6812(spi_bus);
 //! ````
 //!
+//! ## Addressable devices
+//!
+//! Addressable "-2" variants (e.g. LTC6811-2) share a bus instead of being daisy-chained. Each device
+//! has a 4-bit hardware address. After setting the address, commands only target the addressed device.
+//!
+//! ````
+//! use ltc681x::example::ExampleSPIDevice;
+//! use ltc681x::ltc6811::{CellSelection, LTC6811};
+//! use ltc681x::monitor::{ADCMode, LTC681X, LTC681XClient};
+//!
+//! let mut client: LTC681X<_, _, LTC6811, 1> = LTC681X::ltc6811(ExampleSPIDevice::default());
+//!
+//! for address in 0..4 {
+//!     client.set_address(Some(address)).unwrap();
+//!     client.start_conv_cells(ADCMode::Normal, CellSelection::All, false).unwrap();
+//!     // [...] waiting until conversion finished
+//!     let voltages = client.read_voltages(CellSelection::All).unwrap();
+//! }
+//! ````
+//!
+//! # Wake-up
+//!
+//! Devices enter SLEEP state after a timeout without communication. The client sends one wake-up pulse
+//! per device in daisy chain, so that all devices reach STANDBY state.
+//!
+//! ````
+//!# use ltc681x::example::{ExampleDelay, ExampleSPIDevice};
+//!# use ltc681x::ltc6813::LTC6813;
+//!# use ltc681x::monitor::{LTC681X, LTC681XClient};
+//!#
+//!# let mut delay = ExampleDelay {};
+//! let mut client: LTC681X<_, _, LTC6813, 3> = LTC681X::ltc6813(ExampleSPIDevice::default());
+//!
+//! // Wakes up core and isoSPI port from SLEEP state
+//! client.wake_up(&mut delay).unwrap();
+//!
+//! // Wakes up isoSPI port from IDLE state
+//! client.wake_up_idle(&mut delay).unwrap();
+//! ````
+//!
 //! # Conversion
 //!
 //! The following section describes starting conversion and polling mechanisms.
@@ -115,6 +155,37 @@
 //! assert_eq!(1000, timing.alternative);
 //! ````
 //!
+//! ## Combined conversion (ADCVAX and ADCVSC commands)
+//!
+//! All cells may be converted together with GPIO1 and GPIO2 ([LTC681XClient::start_conv_cells_gpio](LTC681XClient#tymethod.start_conv_cells_gpio))
+//! or together with the sum of cells ([LTC681XClient::start_conv_cells_sum](LTC681XClient#tymethod.start_conv_cells_sum))
+//! in a single synchronized command. The results are returned as time-coherent snapshot per device.
+//!
+//! ````
+//!# use ltc681x::example::ExampleSPIDevice;
+//!# use ltc681x::ltc6813::{Channel, LTC6813};
+//!# use ltc681x::monitor::{ADCMode, LTC681X, LTC681XClient};
+//!#
+//!# let mut  client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(ExampleSPIDevice::default());
+//!#
+//! // Converting all cells, GPIO1 and GPIO2 using normal ADC mode
+//! let timing = client.start_conv_cells_gpio(ADCMode::Normal, false).unwrap();
+//! assert_eq!(3157, timing.regular);
+//!
+//! let snapshot = client.read_cells_gpio().unwrap();
+//! assert_eq!(18, snapshot[0].cells.len());
+//! assert_eq!(Channel::GPIO1, snapshot[0].gpio[0].channel);
+//! assert_eq!(Channel::GPIO2, snapshot[0].gpio[1].channel);
+//!
+//! // Converting all cells and the sum of cells using normal ADC mode
+//! let timing = client.start_conv_cells_sum(ADCMode::Normal, false).unwrap();
+//! assert_eq!(2750, timing.regular);
+//!
+//! let snapshot = client.read_cells_sum().unwrap();
+//! assert_eq!(24979, snapshot[0].cells[0].voltage);
+//! assert_eq!(75_318_000, snapshot[0].total_voltage);
+//! ````
+//!
 //! ## Polling
 //!
 //! ADC status may be be polled using the [PollClient::adc_ready](PollClient#tymethod.adc_ready) method.
@@ -145,6 +216,31 @@
 //! }
 //! ````
 //!
+//! ### PLADC command polling
+//!
+//! After sending the PLADC command, the device holds SDO low until all conversions are complete.
+//! CS is released after each poll, so this method works on any [SpiDevice], e.g. on a shared bus.
+//!
+//! Clients created without a dedicated poll method (e.g. by [LTC681X::ltc6813]) are polling by PLADC command as well.
+//!
+//! ````
+//!# use ltc681x::example::ExampleSPIDevice;
+//!# use ltc681x::ltc6813::LTC6813;
+//!# use ltc681x::monitor::{LTC681X, PollClient};
+//!#
+//! let mut  client: LTC681X<_, _, LTC6813, 1> = LTC681X::enable_pladc_polling(ExampleSPIDevice::default());
+//!
+//! while !client.adc_ready().unwrap() {
+//!     // ADC conversion is not finished yet
+//! }
+//!
+//! let mut  client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(ExampleSPIDevice::default());
+//!
+//! while !client.adc_ready().unwrap() {
+//!     // ADC conversion is not finished yet
+//! }
+//! ````
+//!
 //! ## Reading registers
 //!
 //! The content of registers may be directly read. The client returns an array containing three u16,
@@ -230,6 +326,80 @@
 //! assert_eq!(7869, voltages[0][1].voltage);
 //! ````
 //!
+//! ## Digital redundancy (ADAXD and ADSTATD commands)
+//!
+//! GPIO and status conversions may be executed with digital redundancy. In case the redundancy check fails,
+//! the device stores a fault code instead of the result. The checked read methods report these values as
+//! [CheckedValue::RedundancyFault] instead of as voltage.
+//!
+//! ````
+//!# use ltc681x::example::ExampleSPIDevice;
+//!# use ltc681x::ltc6813::{Channel, GPIOSelection, LTC6813};
+//!# use ltc681x::monitor::{ADCMode, CheckedValue, LTC681X, LTC681XClient, StatusGroup};
+//!#
+//!# let mut  client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(ExampleSPIDevice::default());
+//!#
+//! client.start_conv_gpio_redundant(ADCMode::Normal, GPIOSelection::Group2).unwrap();
+//! // [...] waiting until conversion finished
+//! let voltages = client.read_checked_voltages(GPIOSelection::Group2).unwrap();
+//!
+//! assert_eq!(Channel::GPIO2, voltages[0][0].channel);
+//! assert_eq!(CheckedValue::Valid(7867), voltages[0][0].voltage);
+//!
+//! client.measure_internal_parameters_redundant(ADCMode::Normal, StatusGroup::All).unwrap();
+//! // [...] waiting until conversion finished
+//! let data = client.read_checked_internal_device_parameters().unwrap();
+//!
+//! assert_eq!(CheckedValue::Valid(75_318_000), data[0].total_voltage);
+//! ````
+//!
+//! ## Clearing registers (CLRCELL, CLRAUX and CLRSTAT commands)
+//!
+//! Clearing the result registers before a conversion sets them to 0xFFFF. In case a conversion did not run,
+//! the checked read methods report the affected channels as [CheckedValue::NotConverted] instead of
+//! returning stale results.
+//!
+//! ````
+//!# use ltc681x::example::ExampleSPIDevice;
+//!# use ltc681x::ltc6813::{CellSelection, LTC6813};
+//!# use ltc681x::monitor::{ADCMode, CheckedValue, LTC681X, LTC681XClient};
+//!#
+//!# let mut  client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(ExampleSPIDevice::default());
+//!#
+//! client.clear_cell_registers().unwrap();
+//! client.start_conv_cells(ADCMode::Normal, CellSelection::All, false).unwrap();
+//! // [...] waiting until conversion finished
+//! let voltages = client.read_checked_voltages(CellSelection::All).unwrap();
+//!
+//! for voltage in &voltages[0] {
+//!     if voltage.voltage == CheckedValue::NotConverted {
+//!         // Conversion of channel did not run
+//!     }
+//! }
+//! ````
+//!
+//! ## Muting discharge (MUTE and UNMUTE commands)
+//!
+//! All discharge switches of the daisy chain may be paused temporarily, e.g. to avoid balancing noise during
+//! a precise measurement. The configured discharge settings are kept and applied again after unmuting.
+//!
+//! ````
+//!# use ltc681x::example::{ExampleDelay, ExampleSPIDevice};
+//!# use ltc681x::ltc6813::{CellSelection, LTC6813};
+//!# use ltc681x::monitor::{ADCMode, LTC681X, LTC681XClient};
+//!#
+//!# let mut  client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(ExampleSPIDevice::default());
+//!# let mut delay = ExampleDelay {};
+//!#
+//! client.mute().unwrap();
+//! // [...] measurement without discharge
+//! client.unmute().unwrap();
+//!
+//! // Converting and reading cell group 2 while discharge is muted
+//! let voltages = client.measure_cells_muted(ADCMode::Normal, CellSelection::Group2, &mut delay).unwrap();
+//! assert_eq!(3, voltages[0].len());
+//! ````
+//!
 //! # Self-tests
 //!
 //! The LTC681X family supports a number of verification and fault-tests.
@@ -258,6 +428,96 @@
 //! assert_eq!(8591, data[0][3]);
 //! ````
 //!
+//! ## Digital filter self-tests (CVST, AXST and STATST commands)
+//!
+//! The self-tests verify the digital filters and memory of the cell, auxiliary and status registers.
+//! Each test waits until the test is finished and compares the register contents against the
+//! expected test pattern, which depends on the ADC mode and the ADCOPT configuration (CFGAR0).
+//!
+//! For each device in daisy chain, the registers failing the test are returned:
+//! ````
+//!# use ltc681x::example::{ExampleDelay, ExampleSPIDevice};
+//!# use ltc681x::ltc6813::{LTC6813, Register};
+//!# use ltc681x::monitor::{ADCMode, LTC681X, LTC681XClient, SelfTest};
+//!#
+//!# let mut  client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(ExampleSPIDevice::default());
+//!# let mut delay = ExampleDelay {};
+//!#
+//! // Cell voltage registers
+//! let failed = client.cell_self_test(ADCMode::Normal, SelfTest::Test1, false, &mut delay).unwrap();
+//!
+//! // Auxiliary registers
+//! let failed = client.aux_self_test(ADCMode::Normal, SelfTest::Test1, false, &mut delay).unwrap();
+//!
+//! // Status registers
+//! let failed = client.status_self_test(ADCMode::Normal, SelfTest::Test1, false, &mut delay).unwrap();
+//!
+//! // Register content does not match the test pattern
+//! assert_eq!(Register::StatusA, failed[0][0]);
+//! assert_eq!(Register::StatusB, failed[0][1]);
+//! ````
+//!
+//! ## Status register group B
+//!
+//! Decodes the comparator flags of each cell, the thermal shutdown flag, MUXFAIL and the silicon revision.
+//! ````
+//!# use ltc681x::example::ExampleSPIDevice;
+//!# use ltc681x::ltc6813::{Channel, LTC6813};
+//!# use ltc681x::monitor::{LTC681X, LTC681XClient};
+//!#
+//!# let mut  client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(ExampleSPIDevice::default());
+//!#
+//! let status = client.read_status_b().unwrap();
+//!
+//! assert_eq!(Channel::Cell5, status[0].cell_flags[4].channel);
+//! assert!(status[0].cell_flags[4].over_voltage);
+//! assert!(!status[0].cell_flags[4].under_voltage);
+//!
+//! assert!(!status[0].thermal_shutdown);
+//! assert_eq!(1, status[0].revision);
+//! ````
+//!
+//! ## Multiplexer diagnostic (DIAGN command)
+//!
+//! Checks the multiplexer channel decoders. The client waits until the diagnostic is finished
+//! and decodes the MUXFAIL bit of status register B for each device in daisy chain.
+//! ````
+//!# use ltc681x::example::{ExampleDelay, ExampleSPIDevice};
+//!# use ltc681x::ltc6813::LTC6813;
+//!# use ltc681x::monitor::{LTC681X, LTC681XClient};
+//!#
+//!# let mut  client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(ExampleSPIDevice::default());
+//!# let mut delay = ExampleDelay {};
+//!#
+//! let mux_fail = client.diagnose_mux(&mut delay).unwrap();
+//!
+//! // Multiplexer passed the check
+//! assert!(!mux_fail[0]);
+//! ````
+//!
+//! ## Open-wire detection (ADOW command)
+//!
+//! Detecting open cell sense wires. ADOW conversions are executed with pull-up and pull-down current,
+//! using the given delay for waiting until each conversion is finished.
+//! A wire is reported as open based on the pull-up/pull-down delta rule of the datasheet.
+//! ````
+//!# use ltc681x::example::{ExampleDelay, ExampleSPIDevice};
+//!# use ltc681x::ltc6813::{Channel, LTC6813};
+//!# use ltc681x::monitor::{ADCMode, LTC681X, LTC681XClient};
+//!#
+//!# let mut  client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(ExampleSPIDevice::default());
+//!# let mut delay = ExampleDelay {};
+//!#
+//! let result = client.detect_open_wire(ADCMode::Normal, false, &mut delay).unwrap();
+//!
+//! // Lower sense wire of cell 1 (C0)
+//! assert!(!result[0].c0_open);
+//!
+//! // Upper sense wire of cell 1 (C1)
+//! assert_eq!(Channel::Cell1, result[0].wires[0].channel);
+//! assert!(!result[0].wires[0].open);
+//! ````
+//!
 //! ## Internal device parameters (ADSTAT command)
 //!
 //! Measuring internal device parameters and reading the results.
@@ -284,18 +544,36 @@
 //! // Digital power supply voltage in uV => 5.12 V
 //! assert_eq!(5_120_000, data[0].digital_power);
 //! ````
-use crate::config::Configuration;
+use crate::commands::CMD_ST_COMM;
+use crate::config::{Configuration, PWMConfiguration, SControlConfiguration};
 use crate::monitor::Error::BusError;
 use crate::pec15::PEC15;
 use crate::spi::LatchingSpiDevice;
 use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
 use core::slice::Iter;
+use embedded_hal::delay::DelayNs;
 use embedded_hal::digital::OutputPin;
 use embedded_hal::spi::{Operation, SpiBus, SpiDevice};
 use fixed::types::I16F16;
 use heapless::Vec;
 
+/// Register values 0xFF00 - 0xFF0F mark a failed digital redundancy check (ADAXD and ADSTATD commands)
+const REDUNDANCY_FAULT_MASK: u16 = 0xFFF0;
+const REDUNDANCY_FAULT_CODE: u16 = 0xFF00;
+
+/// Value of result registers after clearing (CLRCELL, CLRAUX and CLRSTAT commands)
+const CLEARED_REGISTER_VALUE: u16 = 0xFFFF;
+
+/// Number of ADOW conversions executed per pull-up/pull-down current during open-wire detection
+const OPEN_WIRE_CONVERSIONS: usize = 2;
+
+/// Pull-up/pull-down delta threshold (-400 mV) indicating an open wire, as raw register value
+const OPEN_WIRE_THRESHOLD: i32 = -4000;
+
+/// Maximum execution time of the DIAGN command in microseconds, in case the core is in STANDBY state
+const MUX_DIAGNOSIS_TIME: u32 = 4_500;
+
 /// Poll Strategy
 pub trait PollMethod<B: SpiDevice> {
     /// Gets called by synchronous commands, which not require any waiting/polling (e.g. writing registers)
@@ -311,7 +589,16 @@ impl<B: SpiBus, CS: OutputPin> PollMethod<LatchingSpiDevice<B, CS>> for SDOLineP
     }
 }
 
-/// No ADC polling is used
+/// Polls the ADC status using the PLADC command
+pub struct PLADCPolling {}
+
+impl<B: SpiDevice> PollMethod<B> for PLADCPolling {
+    fn end_sync_command(&self, _bus: &mut B) -> Result<(), B::Error> {
+        Ok(())
+    }
+}
+
+/// No poll method is used for synchronous commands, ADC status may still be polled by PLADC command
 pub struct NoPolling {}
 
 impl<B: SpiDevice> PollMethod<B> for NoPolling {
@@ -376,6 +663,40 @@ impl ToCommandTiming for StatusGroup {
     }
 }
 
+/// Selection of the self-test pattern (ST bits of CVST, AXST and STATST commands)
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum SelfTest {
+    /// Self-test 1
+    Test1 = 0x1,
+    /// Self-test 2
+    Test2 = 0x2,
+}
+
+impl SelfTest {
+    /// Returns the expected register value of the given self-test
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    /// * `adcopt`: True if alternative ADC modes are configured (CFGAR0=1)
+    pub fn expected_result(&self, mode: ADCMode, adcopt: bool) -> u16 {
+        match (self, mode, adcopt) {
+            (SelfTest::Test1, ADCMode::Fast, false) => 0x9565,
+            (SelfTest::Test1, ADCMode::Fast, true) => 0x9553,
+            (SelfTest::Test1, _, _) => 0x9555,
+            (SelfTest::Test2, ADCMode::Fast, false) => 0x6A9A,
+            (SelfTest::Test2, ADCMode::Fast, true) => 0x6AAC,
+            (SelfTest::Test2, _, _) => 0x6AAA,
+        }
+    }
+}
+
+impl ToCommandBitmap for SelfTest {
+    fn to_bitmap(&self) -> u16 {
+        (*self as u16) << 5
+    }
+}
+
 /// Location of a conversion voltage
 pub struct RegisterAddress<T: DeviceTypes> {
     /// Either a cell or GPIO
@@ -405,6 +726,53 @@ pub struct Voltage<T: DeviceTypes> {
     pub voltage: u16,
 }
 
+/// Conversion result, which has been checked for fault codes
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum CheckedValue<V> {
+    /// Valid conversion result
+    Valid(V),
+
+    /// Digital redundancy check failed, the register holds a fault code instead of a result
+    RedundancyFault,
+
+    /// Register still holds the cleared value, no conversion result was written since clearing
+    NotConverted,
+}
+
+impl CheckedValue<u16> {
+    /// Checks the given raw register value for fault codes
+    pub(crate) fn from_register(value: u16) -> Self {
+        if value == CLEARED_REGISTER_VALUE {
+            return CheckedValue::NotConverted;
+        }
+
+        if value & REDUNDANCY_FAULT_MASK == REDUNDANCY_FAULT_CODE {
+            return CheckedValue::RedundancyFault;
+        }
+
+        CheckedValue::Valid(value)
+    }
+
+    /// Applies the given function to a valid value
+    pub(crate) fn map<U, F: FnOnce(u16) -> U>(self, f: F) -> CheckedValue<U> {
+        match self {
+            CheckedValue::Valid(value) => CheckedValue::Valid(f(value)),
+            CheckedValue::RedundancyFault => CheckedValue::RedundancyFault,
+            CheckedValue::NotConverted => CheckedValue::NotConverted,
+        }
+    }
+}
+
+/// Conversion result of a single channel, which has been checked for fault codes
+pub struct CheckedVoltage<T: DeviceTypes> {
+    /// Channel of the voltage
+    pub channel: T::Channel,
+
+    /// Raw register value
+    /// Real voltage: voltage * 100 uV
+    pub voltage: CheckedValue<u16>,
+}
+
 impl<T: DeviceTypes> Copy for Voltage<T> {}
 
 impl<T: DeviceTypes> Clone for Voltage<T> {
@@ -424,6 +792,27 @@ pub enum Error<B: SpiDevice<u8>> {
 
     /// Writing to the given register is not supported
     ReadOnlyRegister,
+
+    /// Read back configuration of the device with the given daisy chain index did not match the written one
+    VerificationFailed(usize),
+
+    /// The requested register does not exist on this device type
+    UnsupportedRegister,
+
+    /// The requested command is not supported by this device type
+    UnsupportedCommand,
+
+    /// Device type of the given data does not match the device type of the daisy chain position with the given index
+    DeviceMismatch(usize),
+
+    /// The given device address exceeds 4 bits
+    InvalidAddress,
+
+    /// The given chain length is zero or exceeds the maximum chain length
+    InvalidChainLength(usize),
+
+    /// The given number of items does not match the number of devices in daisy chain
+    LengthMismatch(usize),
 }
 
 /// Trait for casting command options to command bitmaps
@@ -491,11 +880,38 @@ pub struct CommandTime {
 }
 
 impl CommandTime {
-    pub fn new(regular: u32, alternative: u32) -> Self {
+    pub const fn new(regular: u32, alternative: u32) -> Self {
         Self { regular, alternative }
     }
 }
 
+/// Execution times of a conversion command for each ADC mode
+#[derive(Copy, Clone, Debug)]
+pub struct ConversionTiming {
+    /// Execution time in 27kHz (CFGAR0=0) or 14kHz (CFGAR0=1) mode
+    pub fast: CommandTime,
+
+    /// Execution time in 7kHz (CFGAR0=0) or 3kHz (CFGAR0=1) mode
+    pub normal: CommandTime,
+
+    /// Execution time in 26Hz (CFGAR0=0) or 2kHz (CFGAR0=1) mode
+    pub filtered: CommandTime,
+
+    /// Execution time in 422Hz (CFGAR0=0) or 1kHz (CFGAR0=1) mode
+    pub other: CommandTime,
+}
+
+impl ToCommandTiming for ConversionTiming {
+    fn to_conv_command_timing(&self, mode: ADCMode) -> CommandTime {
+        match mode {
+            ADCMode::Fast => self.fast,
+            ADCMode::Normal => self.normal,
+            ADCMode::Filtered => self.filtered,
+            ADCMode::Other => self.other,
+        }
+    }
+}
+
 /// Collection of internal device parameters, measured by ADSTAT command
 #[derive(Debug)]
 pub struct InternalDeviceParameters {
@@ -513,6 +929,90 @@ pub struct InternalDeviceParameters {
     pub temperature: I16F16,
 }
 
+/// Time-coherent cell and GPIO voltages of a single device, measured by ADCVAX command
+pub struct CellGPIOSnapshot<T: DeviceTypes> {
+    /// Voltages of all cells, ordered by channel
+    pub cells: Vec<Voltage<T>, 18>,
+
+    /// Voltages of GPIO1 and GPIO2
+    pub gpio: Vec<Voltage<T>, 2>,
+}
+
+/// Time-coherent cell voltages and sum of cells of a single device, measured by ADCVSC command
+pub struct CellSumSnapshot<T: DeviceTypes> {
+    /// Voltages of all cells, ordered by channel
+    pub cells: Vec<Voltage<T>, 18>,
+
+    /// Sum of all cells in uV
+    pub total_voltage: u32,
+}
+
+/// Collection of internal device parameters, which have been checked for fault codes
+#[derive(Debug)]
+pub struct CheckedDeviceParameters {
+    /// Sum of all cells in uV
+    pub total_voltage: CheckedValue<u32>,
+
+    /// Voltage of analog power supply in uV
+    pub analog_power: CheckedValue<u32>,
+
+    /// Voltage of digital power supply in uV
+    pub digital_power: CheckedValue<u32>,
+
+    /// Die temperature in °C as fixed-point number
+    pub temperature: CheckedValue<I16F16>,
+}
+
+/// Open-wire state of the upper sense input of a cell, e.g. C3 for cell 3
+#[derive(Copy, Clone)]
+pub struct SenseWire<T: DeviceTypes> {
+    /// Cell channel
+    pub channel: T::Channel,
+
+    /// True if the sense wire is open
+    pub open: bool,
+}
+
+/// Open-wire detection result of a single device
+pub struct OpenWireResult<T: DeviceTypes> {
+    /// True if the lower sense input of the first cell (C0) is open
+    pub c0_open: bool,
+
+    /// State of the upper sense wire of each cell, ordered by channel
+    pub wires: Vec<SenseWire<T>, 18>,
+}
+
+/// Comparator flags of a single cell
+#[derive(Copy, Clone)]
+pub struct CellFlags<T: DeviceTypes> {
+    /// Cell channel
+    pub channel: T::Channel,
+
+    /// True if the cell voltage exceeded the over-voltage threshold (CxOV)
+    pub over_voltage: bool,
+
+    /// True if the cell voltage fell below the under-voltage threshold (CxUV)
+    pub under_voltage: bool,
+}
+
+/// Decoded status register group B of a single device
+pub struct StatusB<T: DeviceTypes> {
+    /// Voltage of digital power supply in uV
+    pub digital_power: u32,
+
+    /// Comparator flags of each cell, ordered by channel
+    pub cell_flags: Vec<CellFlags<T>, 18>,
+
+    /// True if a thermal shutdown occurred (THSD)
+    pub thermal_shutdown: bool,
+
+    /// True if the multiplexer self-test failed (MUXFAIL)
+    pub mux_fail: bool,
+
+    /// Revision code of the device (REV)
+    pub revision: u8,
+}
+
 /// Device specific types
 pub trait DeviceTypes: Send + Sync + Sized + 'static {
     /// Argument for the identification of cell groups, which depends on the exact device type.
@@ -527,12 +1027,40 @@ pub trait DeviceTypes: Send + Sync + Sized + 'static {
     /// Available cells and GPIOs
     type Channel: ChannelIndex + Into<ChannelType> + Copy + Clone + Send + Sync;
 
+    /// Cells of the device, used for configuring the cell discharge
+    type Cell: Into<crate::config::Cell> + Copy + Clone + Send + Sync;
+
+    /// GPIO pins of the device, used for configuring the GPIO pull-down
+    type GPIO: Into<crate::config::GPIO> + Copy + Clone + Send + Sync;
+
     /// Number of battery cells supported by the device
     const CELL_COUNT: usize;
 
     /// Number of GPIO channels
     const GPIO_COUNT: usize;
 
+    /// Cell selection covering all cells of the device
+    const ALL_CELLS: Self::CellSelection;
+
+    /// GPIO selection covering all GPIOs of the device
+    const ALL_GPIOS: Self::GPIOSelection;
+
+    /// Execution times of the combined cell and GPIO conversion (ADCVAX command)
+    const CELL_GPIO_CONV_TIMING: ConversionTiming;
+
+    /// Execution times of the combined cell and sum of cells conversion (ADCVSC command).
+    /// None in case device type does not support the command.
+    const CELL_SUM_CONV_TIMING: Option<ConversionTiming>;
+
+    /// True if the device type supports muting the discharge (MUTE and UNMUTE commands)
+    const DISCHARGE_MUTE: bool;
+
+    /// Time in microseconds the core needs for waking up from SLEEP state (tWAKE)
+    const WAKE_UP_TIME: u32;
+
+    /// Time in microseconds the isoSPI port needs for becoming ready after a wake-up pulse (tREADY)
+    const READY_TIME: u32;
+
     /// Defines the first register storing the results of overlap measurement.
     /// None in case overlap test is not supported.
     const OVERLAP_TEST_REG_1: Option<Self::Register>;
@@ -547,12 +1075,38 @@ pub trait DeviceTypes: Send + Sync + Sized + 'static {
     /// Status group b register
     const REG_STATUS_B: Self::Register;
 
+    /// Auxiliary register D, which holds the comparator flags of cell 13 and above.
+    /// None in case device type has no such register.
+    const REG_AUX_D: Option<Self::Register>;
+
     /// Configuration register A
     const REG_CONF_A: Self::Register;
 
     /// Configuration register B, None in case device type has no second configuration register
     const REG_CONF_B: Option<Self::Register>;
 
+    /// Bits of configuration register A, which are compared when verifying a written configuration.
+    /// Read-only bits (e.g. GPIO pin states) and reserved bits are masked.
+    const REG_CONF_A_MASK: [u8; 6];
+
+    /// Bits of configuration register B, which are compared when verifying a written configuration.
+    const REG_CONF_B_MASK: [u8; 6];
+
+    /// COMM register group used for I2C/SPI communication via GPIO pins
+    const REG_COMM: Self::Register;
+
+    /// PWM register group for cell discharge duty cycles.
+    /// None in case device type has no such register.
+    const REG_PWM: Option<Self::Register>;
+
+    /// S control register group for S pin pulsing.
+    /// None in case device type has no such register.
+    const REG_S_CONTROL: Option<Self::Register>;
+
+    /// PWM/S control register group B, holding the settings of cells 13-18.
+    /// None in case device type has no such register.
+    const REG_PSB: Option<Self::Register>;
+
     /// Conversion factor for calculating the total voltage based on status register value.
     /// S. datasheet SC -> Sum of All Cells Measurement (page. 68 of LTC6813 datasheet)
     const TOTAL_VOLTAGE_FACTOR: u32;
@@ -572,6 +1126,26 @@ pub trait DeviceTypes: Send + Sync + Sized + 'static {
 pub trait LTC681XClient<T: DeviceTypes, const L: usize> {
     type Error;
 
+    /// Wakes up the core and isoSPI port of all devices in daisy chain from SLEEP state
+    ///
+    /// Sends one wake-up pulse per device, each followed by the wake-up time of the core (tWAKE),
+    /// so that all devices reach STANDBY state.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay`: Delay used for waiting on each device
+    fn wake_up<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Self::Error>;
+
+    /// Wakes up the isoSPI port of all devices in daisy chain from IDLE state
+    ///
+    /// Sends one wake-up pulse per device, each followed by the ready time of the isoSPI port (tREADY).
+    /// The core needs to be already awake, otherwise [LTC681XClient::wake_up](LTC681XClient#tymethod.wake_up) is required.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay`: Delay used for waiting on each device
+    fn wake_up_idle<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Self::Error>;
+
     /// Starts ADC conversion of cell voltages
     ///
     /// # Arguments
@@ -594,7 +1168,51 @@ pub trait LTC681XClient<T: DeviceTypes, const L: usize> {
     /// * `channels`: Measures t:he given GPIO group
     fn start_conv_gpio(&mut self, mode: ADCMode, pins: T::GPIOSelection) -> Result<CommandTime, Self::Error>;
 
-    /// Start the  Overlap Measurements (ADOL command)
+    /// Starts GPIOs ADC conversion with digital redundancy (ADAXD command)
+    ///
+    /// Results failing the redundancy check are marked with a fault code, s. [LTC681XClient::read_checked_voltages](LTC681XClient#tymethod.read_checked_voltages)
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    /// * `pins`: Measures the given GPIO group
+    fn start_conv_gpio_redundant(&mut self, mode: ADCMode, pins: T::GPIOSelection) -> Result<CommandTime, Self::Error>;
+
+    /// Starts the combined ADC conversion of all cells, GPIO1 and GPIO2 (ADCVAX command)
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    /// * `dcp`: True if discharge is permitted during conversion
+    fn start_conv_cells_gpio(&mut self, mode: ADCMode, dcp: bool) -> Result<CommandTime, Self::Error>;
+
+    /// Starts the combined ADC conversion of all cells and the sum of cells (ADCVSC command)
+    ///
+    /// Returns [Error::UnsupportedCommand] in case the device type does not support the command.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    /// * `dcp`: True if discharge is permitted during conversion
+    fn start_conv_cells_sum(&mut self, mode: ADCMode, dcp: bool) -> Result<CommandTime, Self::Error>;
+
+    /// Starts the open-wire ADC conversion of cell voltages (ADOW command)
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    /// * `cells`: Measures the given cell group
+    /// * `pull_up`: True for pull-up current, false for pull-down current
+    /// * `dcp`: True if discharge is permitted during conversion
+    fn start_conv_open_wire(
+        &mut self,
+        mode: ADCMode,
+        cells: T::CellSelection,
+        pull_up: bool,
+        dcp: bool,
+    ) -> Result<CommandTime, Self::Error>;
+
+    /// Start the  Overlap Measurements (ADOL command)
     /// Note: This command is not available on LTC6810, as this device only includes one ADC
     ///
     /// # Arguments
@@ -611,6 +1229,32 @@ pub trait LTC681XClient<T: DeviceTypes, const L: usize> {
     /// * `group`: Selection of status parameter to measure
     fn measure_internal_parameters(&mut self, mode: ADCMode, group: StatusGroup) -> Result<CommandTime, Self::Error>;
 
+    /// Starts measuring internal device parameters with digital redundancy (ADSTATD command)
+    ///
+    /// Results failing the redundancy check are marked with a fault code, s. [LTC681XClient::read_checked_internal_device_parameters](LTC681XClient#tymethod.read_checked_internal_device_parameters)
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    /// * `group`: Selection of status parameter to measure
+    fn measure_internal_parameters_redundant(
+        &mut self,
+        mode: ADCMode,
+        group: StatusGroup,
+    ) -> Result<CommandTime, Self::Error>;
+
+    /// Clears all cell voltage registers (CLRCELL command)
+    /// The registers are set to 0xFFFF, which is reported as [CheckedValue::NotConverted] by checked read methods
+    fn clear_cell_registers(&mut self) -> Result<(), Self::Error>;
+
+    /// Clears all auxiliary registers (CLRAUX command)
+    /// The registers are set to 0xFFFF, which is reported as [CheckedValue::NotConverted] by checked read methods
+    fn clear_aux_registers(&mut self) -> Result<(), Self::Error>;
+
+    /// Clears status register group A and the status values of group B (CLRSTAT command)
+    /// The registers are set to 0xFFFF, which is reported as [CheckedValue::NotConverted] by checked read methods
+    fn clear_status_registers(&mut self) -> Result<(), Self::Error>;
+
     /// Reads the values of the given register
     /// Returns one array for each device in daisy chain
     fn read_register(&mut self, register: T::Register) -> Result<[[u16; 3]; L], Self::Error>;
@@ -619,12 +1263,86 @@ pub trait LTC681XClient<T: DeviceTypes, const L: usize> {
     /// One 3-bytes array per device in daisy chain
     fn write_register(&mut self, register: T::Register, data: [[u8; 6]; L]) -> Result<(), Self::Error>;
 
+    /// Writes the PWM duty cycles of cell discharge, one array item per device in daisy chain
+    ///
+    /// Returns [Error::UnsupportedRegister] in case the device type has no PWM register group.
+    fn write_pwm(&mut self, config: [PWMConfiguration<T>; L]) -> Result<(), Self::Error>;
+
+    /// Reads the PWM duty cycles of cell discharge, one array item per device in daisy chain
+    ///
+    /// Returns [Error::UnsupportedRegister] in case the device type has no PWM register group.
+    fn read_pwm(&mut self) -> Result<[PWMConfiguration<T>; L], Self::Error>;
+
+    /// Writes the S pin control, one array item per device in daisy chain
+    ///
+    /// Returns [Error::UnsupportedRegister] in case the device type has no S control register group.
+    fn write_s_control(&mut self, config: [SControlConfiguration<T>; L]) -> Result<(), Self::Error>;
+
+    /// Reads the S pin control, one array item per device in daisy chain
+    ///
+    /// Returns [Error::UnsupportedRegister] in case the device type has no S control register group.
+    fn read_s_control(&mut self) -> Result<[SControlConfiguration<T>; L], Self::Error>;
+
+    /// Starts the S pin pulsing based on the S control register (STSCTRL command)
+    ///
+    /// Returns [Error::UnsupportedCommand] in case the device type has no S control register group.
+    fn start_s_control(&mut self) -> Result<(), Self::Error>;
+
+    /// Resets the S control register, so all S pins are driven high (CLRSCTRL command)
+    ///
+    /// Returns [Error::UnsupportedCommand] in case the device type has no S control register group.
+    fn clear_s_control(&mut self) -> Result<(), Self::Error>;
+
+    /// Pauses all discharge switches, while keeping the configured discharge settings (MUTE command)
+    ///
+    /// Returns [Error::UnsupportedCommand] in case the device type does not support muting.
+    fn mute(&mut self) -> Result<(), Self::Error>;
+
+    /// Resumes the configured discharge after muting (UNMUTE command)
+    ///
+    /// Returns [Error::UnsupportedCommand] in case the device type does not support muting.
+    fn unmute(&mut self) -> Result<(), Self::Error>;
+
+    /// Starts the I2C/SPI communication of the COMM register content (STCOMM command)
+    /// The command is followed by 72 clock cycles for transmitting the three COMM bytes
+    fn start_comm(&mut self) -> Result<(), Self::Error>;
+
+    /// Reads the raw bytes of the COMM register group (RDCOMM command)
+    /// Returns one array for each device in daisy chain
+    fn read_comm(&mut self) -> Result<[[u8; 6]; L], Self::Error>;
+
     /// Writes the configuration, one array item per device in daisy chain
-    fn write_configuration(&mut self, config: [Configuration; L]) -> Result<(), Self::Error>;
+    fn write_configuration(&mut self, config: [Configuration<T>; L]) -> Result<(), Self::Error>;
+
+    /// Writes the configuration and verifies it by reading it back, one array item per device in daisy chain
+    ///
+    /// Only writable bits are compared. In case of a mismatch or an invalid checksum of the read back data,
+    /// writing is retried up to the given number of times. Returns [Error::VerificationFailed] with the
+    /// daisy chain index of the first disagreeing device, if all attempts failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `config`: Configuration, one array item per device in daisy chain
+    /// * `retries`: Number of retries after the first attempt
+    fn write_configuration_verified(
+        &mut self,
+        config: [Configuration<T>; L],
+        retries: usize,
+    ) -> Result<(), Self::Error>;
+
+    /// Reads the configuration registers, one array item per device in daisy chain
+    ///
+    /// In case the device has no second configuration register, the default values are used for it.
+    fn read_configuration(&mut self) -> Result<[Configuration<T>; L], Self::Error>;
 
     /// Reads and returns the conversion result (voltages) of Cell or GPIO group
     /// Returns one vector for each device in daisy chain
     ///
+    /// Raw register values are returned as they are. So channels, which were not converted since clearing the
+    /// registers, are returned as 6.5535 V readings (cleared value 0xFFFF). Callers must use
+    /// [LTC681XClient::read_checked_voltages](LTC681XClient#tymethod.read_checked_voltages) for detecting such stale
+    /// data, which reports these channels as [CheckedValue::NotConverted].
+    ///
     /// Vector needs to have a fixed capacity until feature [generic_const_exprs](<https://github.com/rust-lang/rust/issues/76560>) is stable
     fn read_voltages<R: RegisterLocator<T> + 'static>(
         &mut self,
@@ -633,6 +1351,22 @@ pub trait LTC681XClient<T: DeviceTypes, const L: usize> {
     where
         T: 'static;
 
+    /// Reads and returns the conversion result (voltages) of Cell or GPIO group
+    /// Values holding a fault code or the cleared register value are reported as such instead of as voltage
+    /// Returns one vector for each device in daisy chain
+    fn read_checked_voltages<R: RegisterLocator<T> + 'static>(
+        &mut self,
+        locator: R,
+    ) -> Result<Vec<Vec<CheckedVoltage<T>, 18>, L>, Self::Error>;
+
+    /// Reads the results of the combined cell and GPIO conversion (ADCVAX command)
+    /// Returns one snapshot for each device in daisy chain
+    fn read_cells_gpio(&mut self) -> Result<Vec<CellGPIOSnapshot<T>, L>, Self::Error>;
+
+    /// Reads the results of the combined cell and sum of cells conversion (ADCVSC command)
+    /// Returns one snapshot for each device in daisy chain
+    fn read_cells_sum(&mut self) -> Result<Vec<CellSumSnapshot<T>, L>, Self::Error>;
+
     /// Reads and returns the results of the overlap measurement
     ///
     /// Index 0: Result of ADC A of first cell*
@@ -646,6 +1380,124 @@ pub trait LTC681XClient<T: DeviceTypes, const L: usize> {
     /// Reads internal device parameters measured by ATOL command
     /// Returns one array item for each device in daisy chain
     fn read_internal_device_parameters(&mut self) -> Result<Vec<InternalDeviceParameters, L>, Self::Error>;
+
+    /// Reads internal device parameters measured by ADSTAT or ADSTATD command
+    /// Values holding a fault code or the cleared register value are reported as such instead of as measurement
+    /// Returns one array item for each device in daisy chain
+    fn read_checked_internal_device_parameters(&mut self) -> Result<Vec<CheckedDeviceParameters, L>, Self::Error>;
+
+    /// Reads and decodes status register group B
+    ///
+    /// Comparator flags of cell 13 and above are read from auxiliary register D, if supported by the device.
+    /// Returns one item for each device in daisy chain.
+    fn read_status_b(&mut self) -> Result<Vec<StatusB<T>, L>, Self::Error>;
+
+    /// Runs the digital filter self-test of the cell voltage registers (CVST command)
+    ///
+    /// Waits until the test is finished and compares all cell voltage registers against the
+    /// expected test pattern.
+    /// Returns one vector for each device in daisy chain, containing the registers which failed the test.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    /// * `test`: Self-test pattern
+    /// * `adcopt`: True if alternative ADC modes are configured (CFGAR0=1)
+    /// * `delay`: Delay used for waiting on test results
+    fn cell_self_test<D: DelayNs>(
+        &mut self,
+        mode: ADCMode,
+        test: SelfTest,
+        adcopt: bool,
+        delay: &mut D,
+    ) -> Result<Vec<Vec<T::Register, 6>, L>, Self::Error>;
+
+    /// Runs the digital filter self-test of the auxiliary registers (AXST command)
+    ///
+    /// Waits until the test is finished and compares all GPIO and reference voltages against the
+    /// expected test pattern.
+    /// Returns one vector for each device in daisy chain, containing the registers which failed the test.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    /// * `test`: Self-test pattern
+    /// * `adcopt`: True if alternative ADC modes are configured (CFGAR0=1)
+    /// * `delay`: Delay used for waiting on test results
+    fn aux_self_test<D: DelayNs>(
+        &mut self,
+        mode: ADCMode,
+        test: SelfTest,
+        adcopt: bool,
+        delay: &mut D,
+    ) -> Result<Vec<Vec<T::Register, 6>, L>, Self::Error>;
+
+    /// Runs the digital filter self-test of the status registers (STATST command)
+    ///
+    /// Waits until the test is finished and compares the status register values (SC, ITMP, VA, VD)
+    /// against the expected test pattern.
+    /// Returns one vector for each device in daisy chain, containing the registers which failed the test.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    /// * `test`: Self-test pattern
+    /// * `adcopt`: True if alternative ADC modes are configured (CFGAR0=1)
+    /// * `delay`: Delay used for waiting on test results
+    fn status_self_test<D: DelayNs>(
+        &mut self,
+        mode: ADCMode,
+        test: SelfTest,
+        adcopt: bool,
+        delay: &mut D,
+    ) -> Result<Vec<Vec<T::Register, 6>, L>, Self::Error>;
+
+    /// Runs the multiplexer decoder diagnostic (DIAGN command)
+    ///
+    /// Waits until the diagnostic is finished and reports the MUXFAIL bit of
+    /// [decoded status register B](LTC681XClient#tymethod.read_status_b).
+    /// Returns one item for each device in daisy chain, true in case the multiplexer check failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay`: Delay used for waiting on the diagnostic result
+    fn diagnose_mux<D: DelayNs>(&mut self, delay: &mut D) -> Result<Vec<bool, L>, Self::Error>;
+
+    /// Runs the open-wire detection of all cell sense wires
+    ///
+    /// ADOW conversions are executed with pull-up and pull-down current, while the given delay is
+    /// used for waiting until each conversion is finished.
+    /// Returns one result for each device in daisy chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    /// * `dcp`: True if discharge is permitted during conversion
+    /// * `delay`: Delay used for waiting on conversion results
+    fn detect_open_wire<D: DelayNs>(
+        &mut self,
+        mode: ADCMode,
+        dcp: bool,
+        delay: &mut D,
+    ) -> Result<Vec<OpenWireResult<T>, L>, Self::Error>;
+
+    /// Converts and reads the given cell group while all discharge switches are muted
+    ///
+    /// Discharge is muted before starting the conversion and unmuted after reading the results. Unmuting is
+    /// also attempted in case the conversion or reading failed.
+    /// Returns one vector for each device in daisy chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode`: ADC mode
+    /// * `cells`: Group of cells to be converted
+    /// * `delay`: Delay used for waiting on conversion results
+    fn measure_cells_muted<D: DelayNs>(
+        &mut self,
+        mode: ADCMode,
+        cells: T::CellSelection,
+        delay: &mut D,
+    ) -> Result<Vec<Vec<Voltage<T>, 18>, L>, Self::Error>;
 }
 
 /// Public LTC681X interface for polling ADC status
@@ -669,6 +1521,12 @@ where
     /// Poll method used for type state
     poll_method: P,
 
+    /// Address of the targeted device (addressable "-2" variants), None for daisy chain (broadcast) commands
+    address: Option<u8>,
+
+    /// Number of devices actually connected in daisy chain, L is the upper bound
+    chain_length: usize,
+
     device_types: PhantomData<T>,
 }
 
@@ -681,11 +1539,40 @@ where
         LTC681X {
             bus: spi_device,
             poll_method: NoPolling {},
+            address: None,
+            chain_length: L,
             device_types: PhantomData,
         }
     }
 }
 
+impl<B, P, T> LTC681X<B, P, T, 1>
+where
+    B: SpiDevice<u8>,
+    P: PollMethod<B>,
+    T: DeviceTypes,
+{
+    /// Sets the 4-bit hardware address of the targeted device (addressable "-2" variants, e.g. LTC6811-2)
+    ///
+    /// All following commands are sent as address commands, so only the device with the given address
+    /// responds. None switches back to broadcast commands.
+    ///
+    /// Returns [Error::InvalidAddress] in case the address exceeds 4 bits. The current address is kept in this case.
+    pub fn set_address(&mut self, address: Option<u8>) -> Result<(), Error<B>> {
+        if address.is_some_and(|address| address > 0xF) {
+            return Err(Error::InvalidAddress);
+        }
+
+        self.address = address;
+        Ok(())
+    }
+
+    /// Returns the address of the targeted device, None in case broadcast commands are used
+    pub fn address(&self) -> Option<u8> {
+        self.address
+    }
+}
+
 impl<B, P, T, const L: usize> LTC681XClient<T, L> for LTC681X<B, P, T, L>
 where
     B: SpiDevice<u8>,
@@ -694,6 +1581,16 @@ where
 {
     type Error = Error<B>;
 
+    /// See [LTC681XClient::wake_up](LTC681XClient#tymethod.wake_up)
+    fn wake_up<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<B>> {
+        self.send_wake_up_pulses(T::WAKE_UP_TIME, delay)
+    }
+
+    /// See [LTC681XClient::wake_up_idle](LTC681XClient#tymethod.wake_up_idle)
+    fn wake_up_idle<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<B>> {
+        self.send_wake_up_pulses(T::READY_TIME, delay)
+    }
+
     /// See [LTC681XClient::start_conv_cells](LTC681XClient#tymethod.start_conv_cells)
     fn start_conv_cells(&mut self, mode: ADCMode, cells: T::CellSelection, dcp: bool) -> Result<CommandTime, Error<B>> {
         let mut command: u16 = 0b0000_0010_0110_0000;
@@ -722,6 +1619,79 @@ where
         Ok(channels.to_conv_command_timing(mode))
     }
 
+    /// See [LTC681XClient::start_conv_gpio_redundant](LTC681XClient#tymethod.start_conv_gpio_redundant)
+    fn start_conv_gpio_redundant(
+        &mut self,
+        mode: ADCMode,
+        channels: T::GPIOSelection,
+    ) -> Result<CommandTime, Error<B>> {
+        let mut command: u16 = 0b0000_0100_0000_0000;
+
+        command |= (mode as u16) << 7;
+        command |= channels.to_bitmap();
+
+        self.send_command(command).map_err(Error::BusError)?;
+
+        Ok(channels.to_conv_command_timing(mode))
+    }
+
+    /// See [LTC681XClient::start_conv_cells_gpio](LTC681XClient#tymethod.start_conv_cells_gpio)
+    fn start_conv_cells_gpio(&mut self, mode: ADCMode, dcp: bool) -> Result<CommandTime, Error<B>> {
+        let mut command: u16 = 0b0000_0100_0110_1111;
+
+        command |= (mode as u16) << 7;
+
+        if dcp {
+            command |= 0b0001_0000;
+        }
+
+        self.send_command(command).map_err(Error::BusError)?;
+
+        Ok(T::CELL_GPIO_CONV_TIMING.to_conv_command_timing(mode))
+    }
+
+    /// See [LTC681XClient::start_conv_cells_sum](LTC681XClient#tymethod.start_conv_cells_sum)
+    fn start_conv_cells_sum(&mut self, mode: ADCMode, dcp: bool) -> Result<CommandTime, Error<B>> {
+        let timing = T::CELL_SUM_CONV_TIMING.ok_or(Error::UnsupportedCommand)?;
+        let mut command: u16 = 0b0000_0100_0110_0111;
+
+        command |= (mode as u16) << 7;
+
+        if dcp {
+            command |= 0b0001_0000;
+        }
+
+        self.send_command(command).map_err(Error::BusError)?;
+
+        Ok(timing.to_conv_command_timing(mode))
+    }
+
+    /// See [LTC681XClient::start_conv_open_wire](LTC681XClient#tymethod.start_conv_open_wire)
+    fn start_conv_open_wire(
+        &mut self,
+        mode: ADCMode,
+        cells: T::CellSelection,
+        pull_up: bool,
+        dcp: bool,
+    ) -> Result<CommandTime, Error<B>> {
+        let mut command: u16 = 0b0000_0010_0010_1000;
+
+        command |= (mode as u16) << 7;
+        command |= cells.to_bitmap();
+
+        if pull_up {
+            command |= 0b0100_0000;
+        }
+
+        if dcp {
+            command |= 0b0001_0000;
+        }
+
+        self.send_command(command).map_err(Error::BusError)?;
+
+        Ok(cells.to_conv_command_timing(mode))
+    }
+
     /// See [LTC681XClient::start_conv_gpio](LTC681XClient#tymethod.start_overlap_measurement)
     fn start_overlap_measurement(&mut self, mode: ADCMode, dcp: bool) -> Result<(), Error<B>> {
         let mut command: u16 = 0b0000_0010_0000_0001;
@@ -747,6 +1717,37 @@ where
         Ok(group.to_conv_command_timing(mode))
     }
 
+    /// See [LTC681XClient::measure_internal_parameters_redundant](LTC681XClient#tymethod.measure_internal_parameters_redundant)
+    fn measure_internal_parameters_redundant(
+        &mut self,
+        mode: ADCMode,
+        group: StatusGroup,
+    ) -> Result<CommandTime, Error<B>> {
+        let mut command: u16 = 0b0000_0100_0000_1000;
+
+        command |= (mode as u16) << 7;
+        command |= group.to_bitmap();
+
+        self.send_command(command).map_err(Error::BusError)?;
+
+        Ok(group.to_conv_command_timing(mode))
+    }
+
+    /// See [LTC681XClient::clear_cell_registers](LTC681XClient#tymethod.clear_cell_registers)
+    fn clear_cell_registers(&mut self) -> Result<(), Error<B>> {
+        self.send_command(0b0000_0111_0001_0001).map_err(Error::BusError)
+    }
+
+    /// See [LTC681XClient::clear_aux_registers](LTC681XClient#tymethod.clear_aux_registers)
+    fn clear_aux_registers(&mut self) -> Result<(), Error<B>> {
+        self.send_command(0b0000_0111_0001_0010).map_err(Error::BusError)
+    }
+
+    /// See [LTC681XClient::clear_status_registers](LTC681XClient#tymethod.clear_status_registers)
+    fn clear_status_registers(&mut self) -> Result<(), Error<B>> {
+        self.send_command(0b0000_0111_0001_0011).map_err(Error::BusError)
+    }
+
     /// See [LTC681XClient::read_cell_voltages](LTC681XClient#tymethod.read_register)
     fn read_register(&mut self, register: T::Register) -> Result<[[u16; 3]; L], Error<B>> {
         self.read_daisy_chain(register.to_read_command())
@@ -755,7 +1756,7 @@ where
     /// See [LTC681XClient::read_cell_voltages](LTC681XClient#tymethod.write_register)
     fn write_register(&mut self, register: T::Register, data: [[u8; 6]; L]) -> Result<(), Error<B>> {
         let pre_command = match register.to_write_command() {
-            Ok(command) => command,
+            Ok(command) => self.address_command(command),
             Err(_) => return Err(Error::ReadOnlyRegister),
         };
 
@@ -775,7 +1776,7 @@ where
         let _ = operations.push(Operation::Write(&first_operation));
 
         // Adding data of daisy-chained devices
-        for (i, item) in shifted_data[..L - 1].iter_mut().enumerate() {
+        for (i, item) in shifted_data[..self.chain_length - 1].iter_mut().enumerate() {
             item[..6].copy_from_slice(&data[i + 1]);
             self.add_pec_checksum(item);
             let _ = operations.push(Operation::Write(item));
@@ -787,66 +1788,244 @@ where
         Ok(())
     }
 
-    /// See [LTC681XClient::read_cell_voltages](LTC681XClient#tymethod.write_configuration)
-    fn write_configuration(&mut self, config: [Configuration; L]) -> Result<(), Self::Error> {
-        let mut register_a = [[0x0u8; 6]; L];
-        let mut register_b = [[0x0u8; 6]; L];
-
-        for item in config.iter().enumerate() {
-            register_a[item.0] = item.1.register_a;
-            register_b[item.0] = item.1.register_b;
-        }
+    /// See [LTC681XClient::write_pwm](LTC681XClient#tymethod.write_pwm)
+    fn write_pwm(&mut self, config: [PWMConfiguration<T>; L]) -> Result<(), Error<B>> {
+        let mut register = [[0x0u8; 6]; L];
 
-        self.write_register(T::REG_CONF_A, register_a)?;
+        let mut register_psb = [[0x0u8; 3]; L];
 
-        if let Some(register) = T::REG_CONF_B {
-            self.write_register(register, register_b)?;
+        for (device_index, item) in config.iter().enumerate() {
+            register[device_index].copy_from_slice(&item.register[..6]);
+            register_psb[device_index].copy_from_slice(&item.register[6..]);
         }
 
-        Ok(())
+        self.write_register(T::REG_PWM.ok_or(Error::UnsupportedRegister)?, register)?;
+        self.update_psb(0, register_psb)
     }
 
-    /// See [LTC681XClient::read_cell_voltages](LTC681XClient#tymethod.read_voltages)
-    fn read_voltages<R: RegisterLocator<T> + 'static>(
-        &mut self,
-        locator: R,
-    ) -> Result<Vec<Vec<Voltage<T>, 18>, L>, Self::Error>
-    where
-        T: 'static,
-    {
-        let mut result: Vec<Vec<Voltage<T>, 18>, L> = Vec::new();
+    /// See [LTC681XClient::read_pwm](LTC681XClient#tymethod.read_pwm)
+    fn read_pwm(&mut self) -> Result<[PWMConfiguration<T>; L], Error<B>> {
+        let mut config: [PWMConfiguration<T>; L] = core::array::from_fn(|_| PWMConfiguration::default());
 
-        // One slot for each register
-        // 1. index: register index
-        // 2. index: device index
-        // 3. index: Slot within register
-        let mut register_data = [[[0u16; 3]; L]; 6];
+        let register_pwm = T::REG_PWM.ok_or(Error::UnsupportedRegister)?;
+        let register = self.read_daisy_chain_bytes(register_pwm.to_read_command())?;
+        for (device_index, item) in config.iter_mut().take(self.chain_length).enumerate() {
+            item.register[..6].copy_from_slice(&register[device_index]);
+        }
 
-        // Array for flagging loaded registers, 0 = not loaded, 1 = loaded
-        let mut loaded_registers = [0; 6];
+        if let Some(register_psb) = T::REG_PSB {
+            let register = self.read_daisy_chain_bytes(register_psb.to_read_command())?;
+            for (device_index, item) in config.iter_mut().take(self.chain_length).enumerate() {
+                item.register[6..].copy_from_slice(&register[device_index][..3]);
+            }
+        }
 
-        // Map register data
-        for device_index in 0..L {
-            let _ = result.push(Vec::new());
+        Ok(config)
+    }
 
-            for address in locator.get_locations() {
-                let register_index = address.register.to_index();
+    /// See [LTC681XClient::write_s_control](LTC681XClient#tymethod.write_s_control)
+    fn write_s_control(&mut self, config: [SControlConfiguration<T>; L]) -> Result<(), Error<B>> {
+        let mut register = [[0x0u8; 6]; L];
 
-                // Load register if not done yet
-                if loaded_registers[register_index] == 0 {
-                    register_data[register_index] = self.read_register(address.register)?;
-                    loaded_registers[register_index] = 1;
-                }
+        let mut register_psb = [[0x0u8; 3]; L];
 
-                let voltage = Voltage {
-                    channel: address.channel,
-                    voltage: register_data[register_index][device_index][address.slot],
-                };
+        for (device_index, item) in config.iter().enumerate() {
+            register[device_index].copy_from_slice(&item.register[..6]);
+            register_psb[device_index].copy_from_slice(&item.register[6..]);
+        }
 
-                let _ = result[device_index].push(voltage);
+        self.write_register(T::REG_S_CONTROL.ok_or(Error::UnsupportedRegister)?, register)?;
+        self.update_psb(3, register_psb)
+    }
+
+    /// See [LTC681XClient::read_s_control](LTC681XClient#tymethod.read_s_control)
+    fn read_s_control(&mut self) -> Result<[SControlConfiguration<T>; L], Error<B>> {
+        let mut config: [SControlConfiguration<T>; L] = core::array::from_fn(|_| SControlConfiguration::default());
+
+        let register_s_control = T::REG_S_CONTROL.ok_or(Error::UnsupportedRegister)?;
+        let register = self.read_daisy_chain_bytes(register_s_control.to_read_command())?;
+        for (device_index, item) in config.iter_mut().take(self.chain_length).enumerate() {
+            item.register[..6].copy_from_slice(&register[device_index]);
+        }
+
+        if let Some(register_psb) = T::REG_PSB {
+            let register = self.read_daisy_chain_bytes(register_psb.to_read_command())?;
+            for (device_index, item) in config.iter_mut().take(self.chain_length).enumerate() {
+                item.register[6..].copy_from_slice(&register[device_index][3..]);
             }
         }
 
+        Ok(config)
+    }
+
+    /// See [LTC681XClient::start_s_control](LTC681XClient#tymethod.start_s_control)
+    fn start_s_control(&mut self) -> Result<(), Error<B>> {
+        T::REG_S_CONTROL.ok_or(Error::UnsupportedCommand)?;
+        self.send_command(0b0000_0000_0001_1001).map_err(Error::BusError)
+    }
+
+    /// See [LTC681XClient::clear_s_control](LTC681XClient#tymethod.clear_s_control)
+    fn clear_s_control(&mut self) -> Result<(), Error<B>> {
+        T::REG_S_CONTROL.ok_or(Error::UnsupportedCommand)?;
+        self.send_command(0b0000_0000_0001_1000).map_err(Error::BusError)
+    }
+
+    /// See [LTC681XClient::mute](LTC681XClient#tymethod.mute)
+    fn mute(&mut self) -> Result<(), Error<B>> {
+        if !T::DISCHARGE_MUTE {
+            return Err(Error::UnsupportedCommand);
+        }
+
+        self.send_command(0b0000_0000_0010_1000).map_err(Error::BusError)
+    }
+
+    /// See [LTC681XClient::unmute](LTC681XClient#tymethod.unmute)
+    fn unmute(&mut self) -> Result<(), Error<B>> {
+        if !T::DISCHARGE_MUTE {
+            return Err(Error::UnsupportedCommand);
+        }
+
+        self.send_command(0b0000_0000_0010_1001).map_err(Error::BusError)
+    }
+
+    /// See [LTC681XClient::start_comm](LTC681XClient#tymethod.start_comm)
+    fn start_comm(&mut self) -> Result<(), Error<B>> {
+        // STCOMM command + 9 dummy bytes (72 clock cycles)
+        let mut data = [0xFF_u8; 13];
+        data[..4].copy_from_slice(&self.address_command(CMD_ST_COMM));
+
+        self.bus.write(&data).map_err(BusError)?;
+        self.poll_method.end_sync_command(&mut self.bus).map_err(BusError)
+    }
+
+    /// See [LTC681XClient::read_comm](LTC681XClient#tymethod.read_comm)
+    fn read_comm(&mut self) -> Result<[[u8; 6]; L], Error<B>> {
+        self.read_daisy_chain_bytes(T::REG_COMM.to_read_command())
+    }
+
+    /// See [LTC681XClient::read_cell_voltages](LTC681XClient#tymethod.write_configuration)
+    fn write_configuration(&mut self, config: [Configuration<T>; L]) -> Result<(), Self::Error> {
+        let mut register_a = [[0x0u8; 6]; L];
+        let mut register_b = [[0x0u8; 6]; L];
+
+        for item in config.iter().enumerate() {
+            register_a[item.0] = item.1.register_a;
+            register_b[item.0] = item.1.register_b;
+        }
+
+        self.write_register(T::REG_CONF_A, register_a)?;
+
+        if let Some(register) = T::REG_CONF_B {
+            self.write_register(register, register_b)?;
+        }
+
+        Ok(())
+    }
+
+    /// See [LTC681XClient::write_configuration_verified](LTC681XClient#tymethod.write_configuration_verified)
+    fn write_configuration_verified(
+        &mut self,
+        config: [Configuration<T>; L],
+        retries: usize,
+    ) -> Result<(), Self::Error> {
+        let mut result = Ok(());
+
+        for _ in 0..=retries {
+            self.write_configuration(config.clone())?;
+
+            result = self.verify_configuration(&config);
+            match result {
+                Err(Error::VerificationFailed(_)) | Err(Error::ChecksumMismatch) => continue,
+                _ => return result,
+            }
+        }
+
+        result
+    }
+
+    /// See [LTC681XClient::read_configuration](LTC681XClient#tymethod.read_configuration)
+    fn read_configuration(&mut self) -> Result<[Configuration<T>; L], Self::Error> {
+        let mut config: [Configuration<T>; L] = core::array::from_fn(|_| Configuration::default());
+
+        let register_a = self.read_daisy_chain_bytes(T::REG_CONF_A.to_read_command())?;
+        for (device_index, item) in config.iter_mut().take(self.chain_length).enumerate() {
+            item.register_a = register_a[device_index];
+        }
+
+        if let Some(register) = T::REG_CONF_B {
+            let register_b = self.read_daisy_chain_bytes(register.to_read_command())?;
+            for (device_index, item) in config.iter_mut().take(self.chain_length).enumerate() {
+                item.register_b = register_b[device_index];
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// See [LTC681XClient::read_cell_voltages](LTC681XClient#tymethod.read_voltages)
+    fn read_voltages<R: RegisterLocator<T> + 'static>(
+        &mut self,
+        locator: R,
+    ) -> Result<Vec<Vec<Voltage<T>, 18>, L>, Self::Error>
+    where
+        T: 'static,
+    {
+        self.map_voltages(locator.get_locations())
+    }
+
+    /// See [LTC681XClient::read_checked_voltages](LTC681XClient#tymethod.read_checked_voltages)
+    fn read_checked_voltages<R: RegisterLocator<T> + 'static>(
+        &mut self,
+        locator: R,
+    ) -> Result<Vec<Vec<CheckedVoltage<T>, 18>, L>, Self::Error> {
+        let voltages: Vec<Vec<Voltage<T>, 18>, L> = self.map_voltages(locator.get_locations())?;
+
+        let mut result = Vec::new();
+        for device_voltages in voltages {
+            let mut checked = Vec::new();
+            for voltage in device_voltages {
+                let _ = checked.push(CheckedVoltage {
+                    channel: voltage.channel,
+                    voltage: CheckedValue::from_register(voltage.voltage),
+                });
+            }
+
+            let _ = result.push(checked);
+        }
+
+        Ok(result)
+    }
+
+    /// See [LTC681XClient::read_cells_gpio](LTC681XClient#tymethod.read_cells_gpio)
+    fn read_cells_gpio(&mut self) -> Result<Vec<CellGPIOSnapshot<T>, L>, Self::Error> {
+        let cells = self.map_voltages(T::ALL_CELLS.get_locations())?;
+        let gpio = self.map_voltages(
+            T::ALL_GPIOS
+                .get_locations()
+                .filter(|address| matches!(address.channel.to_gpio_index(), Some(0) | Some(1))),
+        )?;
+
+        let mut result = Vec::new();
+        for (cells, gpio) in cells.into_iter().zip(gpio) {
+            let _ = result.push(CellGPIOSnapshot { cells, gpio });
+        }
+
+        Ok(result)
+    }
+
+    /// See [LTC681XClient::read_cells_sum](LTC681XClient#tymethod.read_cells_sum)
+    fn read_cells_sum(&mut self) -> Result<Vec<CellSumSnapshot<T>, L>, Self::Error> {
+        let cells = self.map_voltages(T::ALL_CELLS.get_locations())?;
+        let status_a = self.read_register(T::REG_STATUS_A)?;
+
+        let mut result = Vec::new();
+        for (device_index, cells) in cells.into_iter().enumerate() {
+            let _ = result.push(CellSumSnapshot {
+                cells,
+                total_voltage: status_a[device_index][0] as u32 * T::TOTAL_VOLTAGE_FACTOR * 100,
+            });
+        }
+
         Ok(result)
     }
 
@@ -866,7 +2045,7 @@ where
             [[0; 3]; L]
         };
 
-        for device_index in 0..L {
+        for device_index in 0..self.chain_length {
             data[device_index][0] = register_c[device_index][0];
             data[device_index][1] = register_c[device_index][1];
             data[device_index][2] = register_e[device_index][0];
@@ -883,7 +2062,7 @@ where
 
         let mut parameters = Vec::new();
 
-        for device_index in 0..L {
+        for device_index in 0..self.chain_length {
             let temp_fixed = self.calc_temperature(status_a[device_index][1]);
 
             let _ = parameters.push(InternalDeviceParameters {
@@ -896,6 +2075,202 @@ where
 
         Ok(parameters)
     }
+
+    /// See [LTC681XClient::read_checked_internal_device_parameters](LTC681XClient#tymethod.read_checked_internal_device_parameters)
+    fn read_checked_internal_device_parameters(&mut self) -> Result<Vec<CheckedDeviceParameters, L>, Self::Error> {
+        let status_a = self.read_register(T::REG_STATUS_A)?;
+        let status_b = self.read_register(T::REG_STATUS_B)?;
+
+        let mut parameters = Vec::new();
+
+        for device_index in 0..self.chain_length {
+            let _ = parameters.push(CheckedDeviceParameters {
+                total_voltage: CheckedValue::from_register(status_a[device_index][0])
+                    .map(|value| value as u32 * T::TOTAL_VOLTAGE_FACTOR * 100),
+                analog_power: CheckedValue::from_register(status_a[device_index][2]).map(|value| value as u32 * 100),
+                digital_power: CheckedValue::from_register(status_b[device_index][0]).map(|value| value as u32 * 100),
+                temperature: CheckedValue::from_register(status_a[device_index][1])
+                    .map(|value| self.calc_temperature(value)),
+            });
+        }
+
+        Ok(parameters)
+    }
+
+    /// See [LTC681XClient::read_status_b](LTC681XClient#tymethod.read_status_b)
+    fn read_status_b(&mut self) -> Result<Vec<StatusB<T>, L>, Self::Error> {
+        let status_b = self.read_register(T::REG_STATUS_B)?;
+
+        let aux_d = match T::REG_AUX_D {
+            Some(register) => Some(self.read_register(register)?),
+            _ => None,
+        };
+
+        let mut result = Vec::new();
+
+        for device_index in 0..self.chain_length {
+            let words = status_b[device_index];
+
+            // Two bits per cell (UV, OV), cells 1-8 in STBR2-3 and cells 9-12 in STBR4
+            let mut flags = words[1] as u64 | ((words[2] & 0x00FF) as u64) << 16;
+
+            // Cells 13-18 in AVDR4-5
+            if let Some(aux_d) = &aux_d {
+                flags |= ((aux_d[device_index][2] & 0x0FFF) as u64) << 24;
+            }
+
+            let mut cell_flags = Vec::new();
+            for (cell_index, address) in T::ALL_CELLS.get_locations().enumerate() {
+                let _ = cell_flags.push(CellFlags {
+                    channel: address.channel,
+                    under_voltage: (flags >> (cell_index * 2)) & 0b1 != 0,
+                    over_voltage: (flags >> (cell_index * 2 + 1)) & 0b1 != 0,
+                });
+            }
+
+            let _ = result.push(StatusB {
+                digital_power: words[0] as u32 * 100,
+                cell_flags,
+                thermal_shutdown: words[2] & 0x0100 != 0,
+                mux_fail: words[2] & 0x0200 != 0,
+                revision: (words[2] >> 12) as u8,
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// See [LTC681XClient::cell_self_test](LTC681XClient#tymethod.cell_self_test)
+    fn cell_self_test<D: DelayNs>(
+        &mut self,
+        mode: ADCMode,
+        test: SelfTest,
+        adcopt: bool,
+        delay: &mut D,
+    ) -> Result<Vec<Vec<T::Register, 6>, L>, Self::Error> {
+        let mut command: u16 = 0b0000_0010_0000_0111;
+
+        command |= (mode as u16) << 7;
+        command |= test.to_bitmap();
+
+        self.send_command(command).map_err(Error::BusError)?;
+        self.wait_for_conversion(T::ALL_CELLS.to_conv_command_timing(mode), delay)?;
+
+        let locations = T::ALL_CELLS.get_locations().map(|address| (address.register, address.slot));
+        self.verify_self_test(locations, test.expected_result(mode, adcopt))
+    }
+
+    /// See [LTC681XClient::aux_self_test](LTC681XClient#tymethod.aux_self_test)
+    fn aux_self_test<D: DelayNs>(
+        &mut self,
+        mode: ADCMode,
+        test: SelfTest,
+        adcopt: bool,
+        delay: &mut D,
+    ) -> Result<Vec<Vec<T::Register, 6>, L>, Self::Error> {
+        let mut command: u16 = 0b0000_0100_0000_0111;
+
+        command |= (mode as u16) << 7;
+        command |= test.to_bitmap();
+
+        self.send_command(command).map_err(Error::BusError)?;
+        self.wait_for_conversion(T::ALL_GPIOS.to_conv_command_timing(mode), delay)?;
+
+        let locations = T::ALL_GPIOS.get_locations().map(|address| (address.register, address.slot));
+        self.verify_self_test(locations, test.expected_result(mode, adcopt))
+    }
+
+    /// See [LTC681XClient::status_self_test](LTC681XClient#tymethod.status_self_test)
+    fn status_self_test<D: DelayNs>(
+        &mut self,
+        mode: ADCMode,
+        test: SelfTest,
+        adcopt: bool,
+        delay: &mut D,
+    ) -> Result<Vec<Vec<T::Register, 6>, L>, Self::Error> {
+        let mut command: u16 = 0b0000_0100_0000_1111;
+
+        command |= (mode as u16) << 7;
+        command |= test.to_bitmap();
+
+        self.send_command(command).map_err(Error::BusError)?;
+        self.wait_for_conversion(StatusGroup::All.to_conv_command_timing(mode), delay)?;
+
+        // SC, ITMP and VA are stored in status register A, VD in the first slot of status register B
+        let locations = [
+            (T::REG_STATUS_A, 0),
+            (T::REG_STATUS_A, 1),
+            (T::REG_STATUS_A, 2),
+            (T::REG_STATUS_B, 0),
+        ];
+        self.verify_self_test(locations.into_iter(), test.expected_result(mode, adcopt))
+    }
+
+    /// See [LTC681XClient::diagnose_mux](LTC681XClient#tymethod.diagnose_mux)
+    fn diagnose_mux<D: DelayNs>(&mut self, delay: &mut D) -> Result<Vec<bool, L>, Self::Error> {
+        self.send_command(0b0000_0111_0001_0101).map_err(Error::BusError)?;
+        self.wait_for_conversion(CommandTime::new(MUX_DIAGNOSIS_TIME, MUX_DIAGNOSIS_TIME), delay)?;
+
+        Ok(self.read_status_b()?.iter().map(|status| status.mux_fail).collect())
+    }
+
+    /// See [LTC681XClient::detect_open_wire](LTC681XClient#tymethod.detect_open_wire)
+    fn detect_open_wire<D: DelayNs>(
+        &mut self,
+        mode: ADCMode,
+        dcp: bool,
+        delay: &mut D,
+    ) -> Result<Vec<OpenWireResult<T>, L>, Self::Error> {
+        let pull_up = self.measure_open_wire(mode, true, dcp, delay)?;
+        let pull_down = self.measure_open_wire(mode, false, dcp, delay)?;
+
+        let mut result = Vec::new();
+
+        for (up, down) in pull_up.iter().zip(pull_down.iter()) {
+            let mut wires = Vec::new();
+            for (i, cell) in up.iter().enumerate() {
+                // C(n) is checked by the delta of cell n + 1, except the top wire which is checked directly
+                let open = match up.get(i + 1) {
+                    Some(next) => (next.voltage as i32 - down[i + 1].voltage as i32) < OPEN_WIRE_THRESHOLD,
+                    None => down[i].voltage == 0,
+                };
+
+                let _ = wires.push(SenseWire {
+                    channel: cell.channel,
+                    open,
+                });
+            }
+
+            let _ = result.push(OpenWireResult {
+                c0_open: up.first().map(|cell| cell.voltage == 0).unwrap_or(false),
+                wires,
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// See [LTC681XClient::measure_cells_muted](LTC681XClient#tymethod.measure_cells_muted)
+    fn measure_cells_muted<D: DelayNs>(
+        &mut self,
+        mode: ADCMode,
+        cells: T::CellSelection,
+        delay: &mut D,
+    ) -> Result<Vec<Vec<Voltage<T>, 18>, L>, Self::Error> {
+        self.mute()?;
+
+        let result = self
+            .start_conv_cells(mode, cells, false)
+            .and_then(|timing| self.wait_for_conversion(timing, delay))
+            .and_then(|_| self.read_voltages(cells));
+
+        // Discharge is resumed in any case, while the first error is reported
+        let unmute_result = self.unmute();
+        let voltages = result?;
+        unmute_result?;
+
+        Ok(voltages)
+    }
 }
 
 impl<B, P, T, const L: usize> LTC681X<B, P, T, L>
@@ -904,15 +2279,120 @@ where
     P: PollMethod<B>,
     T: DeviceTypes,
 {
+    /// Reads the voltages of the given register locations
+    /// Each register is just read once, even if it stores multiple voltages
+    fn map_voltages<'a, I, const C: usize>(&mut self, locations: I) -> Result<Vec<Vec<Voltage<T>, C>, L>, Error<B>>
+    where
+        I: Iterator<Item = &'a RegisterAddress<T>> + Clone,
+    {
+        let mut result: Vec<Vec<Voltage<T>, C>, L> = Vec::new();
+
+        // One slot for each register
+        // 1. index: register index
+        // 2. index: device index
+        // 3. index: Slot within register
+        let mut register_data = [[[0u16; 3]; L]; 6];
+
+        // Array for flagging loaded registers, 0 = not loaded, 1 = loaded
+        let mut loaded_registers = [0; 6];
+
+        // Map register data
+        for device_index in 0..self.chain_length {
+            let _ = result.push(Vec::new());
+
+            for address in locations.clone() {
+                let register_index = address.register.to_index();
+
+                // Load register if not done yet
+                if loaded_registers[register_index] == 0 {
+                    register_data[register_index] = self.read_register(address.register)?;
+                    loaded_registers[register_index] = 1;
+                }
+
+                let voltage = Voltage {
+                    channel: address.channel,
+                    voltage: register_data[register_index][device_index][address.slot],
+                };
+
+                let _ = result[device_index].push(voltage);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Reads back the configuration and compares the writable bits with the expected configuration
+    fn verify_configuration(&mut self, expected: &[Configuration<T>; L]) -> Result<(), Error<B>> {
+        let actual = self.read_configuration()?;
+
+        for (device_index, (actual, expected)) in actual.iter().zip(expected.iter()).take(self.chain_length).enumerate()
+        {
+            for i in 0..6 {
+                if (actual.register_a[i] ^ expected.register_a[i]) & T::REG_CONF_A_MASK[i] != 0 {
+                    return Err(Error::VerificationFailed(device_index));
+                }
+
+                if T::REG_CONF_B.is_some()
+                    && (actual.register_b[i] ^ expected.register_b[i]) & T::REG_CONF_B_MASK[i] != 0
+                {
+                    return Err(Error::VerificationFailed(device_index));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Updates the PWM (offset 0) or S control (offset 3) half of PSB register group, while keeping the other half
+    /// Does nothing in case the device type has no PSB register group
+    fn update_psb(&mut self, offset: usize, data: [[u8; 3]; L]) -> Result<(), Error<B>> {
+        let register = match T::REG_PSB {
+            None => return Ok(()),
+            Some(register) => register,
+        };
+
+        let mut register_data = self.read_daisy_chain_bytes(register.to_read_command())?;
+        for (device_index, item) in register_data.iter_mut().enumerate() {
+            item[offset..offset + 3].copy_from_slice(&data[device_index]);
+        }
+
+        self.write_register(register, register_data)
+    }
+
+    /// Sends one dummy byte per device in daisy chain, each followed by the given delay
+    fn send_wake_up_pulses<D: DelayNs>(&mut self, time: u32, delay: &mut D) -> Result<(), Error<B>> {
+        for _ in 0..self.chain_length {
+            self.bus.write(&[0xFF]).map_err(Error::BusError)?;
+            self.poll_method.end_sync_command(&mut self.bus).map_err(Error::BusError)?;
+            delay.delay_us(time);
+        }
+
+        Ok(())
+    }
+
     /// Sends the given command. Calculates and attaches the PEC checksum
     fn send_command(&mut self, command: u16) -> Result<(), B::Error> {
         let mut data = [(command >> 8) as u8, command as u8, 0x0, 0x0];
         self.add_pec_checksum(&mut data);
+        let data = self.address_command(data);
 
         self.bus.write(&data)?;
         Ok(())
     }
 
+    /// Converts the given full command into an address command, in case a device address is set
+    /// CMD0 holds the address command flag and the 4-bit address, the PEC checksum is recalculated
+    fn address_command(&self, command: [u8; 4]) -> [u8; 4] {
+        let address = match self.address {
+            None => return command,
+            Some(address) => address,
+        };
+
+        let mut data = [0x80 | (address << 3) | (command[0] & 0b0000_0111), command[1], 0x0, 0x0];
+        self.add_pec_checksum(&mut data);
+        data
+    }
+
     /// Calculates and attaches the PEC15 checksum
     fn add_pec_checksum(&self, data: &mut [u8]) {
         let pec = PEC15::calc(&data[0..data.len() - 2]);
@@ -923,17 +2403,12 @@ where
 
     /// Send the given read command and returns the response of all devices in daisy chain
     fn read_daisy_chain(&mut self, command: [u8; 4]) -> Result<[[u16; 3]; L], Error<B>> {
-        let data = self.read_trans_daisy_chain(command)?;
+        let data = self.read_daisy_chain_bytes(command)?;
 
         let mut result = [[0, 0, 0]; L];
-        for (i, item) in result.iter_mut().take(L).enumerate() {
+        for (i, item) in result.iter_mut().take(self.chain_length).enumerate() {
             let response = data[i];
 
-            let pec = PEC15::calc(&response[0..6]);
-            if pec[0] != response[6] || pec[1] != response[7] {
-                return Err(Error::ChecksumMismatch);
-            }
-
             item[0] = response[0] as u16;
             item[0] |= (response[1] as u16) << 8;
 
@@ -944,12 +2419,51 @@ where
             item[2] |= (response[5] as u16) << 8;
         }
 
+        Ok(result)
+    }
+
+    /// Send the given read command and returns the raw register bytes of all devices in daisy chain
+    fn read_daisy_chain_bytes(&mut self, command: [u8; 4]) -> Result<[[u8; 6]; L], Error<B>> {
+        let data = self.read_trans_daisy_chain(command)?;
+
+        let mut result = [[0; 6]; L];
+        for (i, item) in result.iter_mut().take(self.chain_length).enumerate() {
+            let response = data[i];
+
+            let pec = PEC15::calc(&response[0..6]);
+            if pec[0] != response[6] || pec[1] != response[7] {
+                return Err(Error::ChecksumMismatch);
+            }
+
+            item.copy_from_slice(&response[0..6]);
+        }
+
         self.poll_method.end_sync_command(&mut self.bus).map_err(Error::BusError)?;
         Ok(result)
     }
 
+    /// Sets the number of devices actually connected in daisy chain
+    pub(crate) fn set_chain_length(&mut self, length: usize) {
+        self.chain_length = length;
+    }
+
+    /// Returns the number of devices actually connected in daisy chain
+    pub(crate) fn chain_length(&self) -> usize {
+        self.chain_length
+    }
+
+    /// Sends the read command of the given register and returns the raw response (data + PEC) of all devices in
+    /// daisy chain. The PEC is not verified, as devices lacking the register may respond with invalid data.
+    pub(crate) fn read_register_frames(&mut self, register: T::Register) -> Result<[[u8; 8]; L], Error<B>> {
+        let data = self.read_trans_daisy_chain(register.to_read_command())?;
+
+        self.poll_method.end_sync_command(&mut self.bus).map_err(Error::BusError)?;
+        Ok(data)
+    }
+
     /// Creates SPI transactions for reading from daisy chain and returns the raw data
     fn read_trans_daisy_chain(&mut self, command: [u8; 4]) -> Result<[[u8; 8]; L], Error<B>> {
+        let command = self.address_command(command);
         let command_write = [
             command[0], command[1], command[2], command[3], 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
         ];
@@ -965,7 +2479,7 @@ where
         let _ = operations.push(Operation::Transfer(&mut command_read, &command_write));
 
         // Read operations for all dasi-chained devices
-        for buffer_item in &mut buffers[1..].iter_mut() {
+        for buffer_item in &mut buffers[1..self.chain_length].iter_mut() {
             operations.push(Operation::Read(buffer_item)).unwrap()
         }
 
@@ -976,6 +2490,89 @@ where
         Ok(buffers)
     }
 
+    /// Runs the ADOW conversion of all cells and returns the resulting cell voltages
+    fn measure_open_wire<D: DelayNs>(
+        &mut self,
+        mode: ADCMode,
+        pull_up: bool,
+        dcp: bool,
+        delay: &mut D,
+    ) -> Result<Vec<Vec<Voltage<T>, 18>, L>, Error<B>> {
+        for _ in 0..OPEN_WIRE_CONVERSIONS {
+            let timing = self.start_conv_open_wire(mode, T::ALL_CELLS, pull_up, dcp)?;
+            self.wait_for_conversion(timing, delay)?;
+        }
+
+        self.read_voltages(T::ALL_CELLS)
+    }
+
+    /// Waits for the longest possible execution time of the issued command
+    fn wait_for_conversion<D: DelayNs>(&mut self, timing: CommandTime, delay: &mut D) -> Result<(), Error<B>> {
+        delay.delay_us(timing.regular.max(timing.alternative));
+        self.poll_method.end_sync_command(&mut self.bus).map_err(Error::BusError)
+    }
+
+    /// Compares the given register slots against the expected self-test result
+    /// Returns the failed registers for each device in daisy chain
+    fn verify_self_test<I: Iterator<Item = (T::Register, usize)>>(
+        &mut self,
+        locations: I,
+        expected: u16,
+    ) -> Result<Vec<Vec<T::Register, 6>, L>, Error<B>> {
+        let mut result: Vec<Vec<T::Register, 6>, L> = Vec::new();
+        for _ in 0..self.chain_length {
+            let _ = result.push(Vec::new());
+        }
+
+        // One slot for each register
+        // 1. index: register index
+        // 2. index: device index
+        // 3. index: Slot within register
+        let mut register_data = [[[0u16; 3]; L]; 6];
+
+        // Array for flagging loaded registers
+        let mut loaded_registers = [false; 6];
+
+        // Array for flagging already reported registers per device
+        let mut failed_registers = [[false; 6]; L];
+
+        for (register, slot) in locations {
+            let register_index = register.to_index();
+
+            if !loaded_registers[register_index] {
+                register_data[register_index] = self.read_register(register)?;
+                loaded_registers[register_index] = true;
+            }
+
+            for device_index in 0..self.chain_length {
+                if register_data[register_index][device_index][slot] != expected
+                    && !failed_registers[device_index][register_index]
+                {
+                    failed_registers[device_index][register_index] = true;
+                    let _ = result[device_index].push(register);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Sends the PLADC command and returns true if SDO is released, i.e. all conversions are complete
+    fn poll_pladc(&mut self) -> Result<bool, Error<B>> {
+        let mut pladc = [0b0000_0111, 0b0001_0100, 0x0, 0x0];
+        self.add_pec_checksum(&mut pladc);
+
+        let mut command = [0xFF; 5];
+        command[..4].copy_from_slice(&self.address_command(pladc));
+
+        let mut buffer = [0x0; 5];
+        self.bus
+            .transaction(&mut [Operation::Transfer(&mut buffer, &command)])
+            .map_err(Error::BusError)?;
+
+        Ok(buffer[4] == 0xFF)
+    }
+
     /// Calculates the temperature in °C based on raw register value
     fn calc_temperature(&self, value: u16) -> I16F16 {
         if value >= 53744 {
@@ -1005,6 +2602,8 @@ where
         LTC681X {
             bus: LatchingSpiDevice::new(bus, cs),
             poll_method: SDOLinePolling {},
+            address: None,
+            chain_length: L,
             device_types: PhantomData,
         }
     }
@@ -1033,12 +2632,153 @@ where
     }
 }
 
+impl<B, T, const L: usize> LTC681X<B, PLADCPolling, T, L>
+where
+    B: SpiDevice<u8>,
+    T: DeviceTypes,
+{
+    /// Enables ADC polling by PLADC command
+    ///
+    /// The device holds SDO low while a conversion is in progress. In contrast to SDO line polling,
+    /// CS is released after each poll, so the SPI bus may be shared with other devices.
+    pub fn enable_pladc_polling(bus: B) -> LTC681X<B, PLADCPolling, T, L> {
+        LTC681X {
+            bus,
+            poll_method: PLADCPolling {},
+            address: None,
+            chain_length: L,
+            device_types: PhantomData,
+        }
+    }
+}
+
+impl<B, T, const L: usize> PollClient for LTC681X<B, PLADCPolling, T, L>
+where
+    B: SpiDevice<u8>,
+    T: DeviceTypes,
+{
+    type Error = Error<B>;
+
+    /// Sends the PLADC command and returns false if the ADC is busy
+    fn adc_ready(&mut self) -> Result<bool, Self::Error> {
+        self.poll_pladc()
+    }
+}
+
+impl<B, T, const L: usize> PollClient for LTC681X<B, NoPolling, T, L>
+where
+    B: SpiDevice<u8>,
+    T: DeviceTypes,
+{
+    type Error = Error<B>;
+
+    /// Sends the PLADC command and returns false if the ADC is busy
+    fn adc_ready(&mut self) -> Result<bool, Self::Error> {
+        self.poll_pladc()
+    }
+}
+
 impl<B: SpiDevice<u8>> Debug for Error<B> {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::BusError(_) => f.debug_struct("BusError").finish(),
             Error::ChecksumMismatch => f.debug_struct("ChecksumMismatch").finish(),
             Error::ReadOnlyRegister => f.debug_struct("ReadOnlyRegister").finish(),
+            Error::VerificationFailed(device_index) => f.debug_tuple("VerificationFailed").field(device_index).finish(),
+            Error::UnsupportedRegister => f.debug_struct("UnsupportedRegister").finish(),
+            Error::UnsupportedCommand => f.debug_struct("UnsupportedCommand").finish(),
+            Error::DeviceMismatch(position) => f.debug_tuple("DeviceMismatch").field(position).finish(),
+            Error::InvalidAddress => f.debug_struct("InvalidAddress").finish(),
+            Error::InvalidChainLength(length) => f.debug_tuple("InvalidChainLength").field(length).finish(),
+            Error::LengthMismatch(length) => f.debug_tuple("LengthMismatch").field(length).finish(),
         }
     }
 }
+
+impl<T: DeviceTypes> Debug for SenseWire<T>
+where
+    T::Channel: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SenseWire")
+            .field("channel", &self.channel)
+            .field("open", &self.open)
+            .finish()
+    }
+}
+
+impl<T: DeviceTypes> Debug for OpenWireResult<T>
+where
+    T::Channel: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OpenWireResult")
+            .field("c0_open", &self.c0_open)
+            .field("wires", &self.wires)
+            .finish()
+    }
+}
+
+impl<T: DeviceTypes> Debug for CellFlags<T>
+where
+    T::Channel: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CellFlags")
+            .field("channel", &self.channel)
+            .field("over_voltage", &self.over_voltage)
+            .field("under_voltage", &self.under_voltage)
+            .finish()
+    }
+}
+
+impl<T: DeviceTypes> Debug for StatusB<T>
+where
+    T::Channel: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StatusB")
+            .field("digital_power", &self.digital_power)
+            .field("cell_flags", &self.cell_flags)
+            .field("thermal_shutdown", &self.thermal_shutdown)
+            .field("mux_fail", &self.mux_fail)
+            .field("revision", &self.revision)
+            .finish()
+    }
+}
+
+impl<T: DeviceTypes> Debug for CellGPIOSnapshot<T>
+where
+    Voltage<T>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CellGPIOSnapshot")
+            .field("cells", &self.cells)
+            .field("gpio", &self.gpio)
+            .finish()
+    }
+}
+
+impl<T: DeviceTypes> Debug for CellSumSnapshot<T>
+where
+    Voltage<T>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CellSumSnapshot")
+            .field("cells", &self.cells)
+            .field("total_voltage", &self.total_voltage)
+            .finish()
+    }
+}
+
+impl<T: DeviceTypes> Debug for CheckedVoltage<T>
+where
+    T::Channel: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CheckedVoltage")
+            .field("channel", &self.channel)
+            .field("voltage", &self.voltage)
+            .finish()
+    }
+}
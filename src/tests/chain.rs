@@ -0,0 +1,318 @@
+//! Tests for daisy chains mixing different device types
+use crate::chain::{ChainConfiguration, ChainDevice, ChainVoltages, MixedChain};
+use crate::config::Configuration;
+use crate::ltc6810::LTC6810;
+use crate::ltc6811::LTC6811;
+use crate::ltc6813::LTC6813;
+use crate::mocks::DeviceMockBuilder;
+use crate::monitor::{ADCMode, Error, LTC681X};
+use crate::{ltc6810, ltc6811, ltc6813};
+
+const FRAME_A: [u8; 8] = [0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C];
+const FRAME_B: [u8; 8] = [0xDD, 0x66, 0x72, 0x1D, 0xA2, 0x1C, 0x11, 0x94];
+const FRAME_C: [u8; 8] = [0x61, 0x63, 0xBD, 0x1E, 0xE4, 0x22, 0x3F, 0x42];
+const FRAME_D: [u8; 8] = [0xB4, 0x61, 0xDD, 0x1E, 0x6C, 0x22, 0x76, 0xF4];
+const FRAME_E: [u8; 8] = [0xDE, 0x64, 0x8F, 0x21, 0x8A, 0x21, 0x8F, 0xDA];
+const FRAME_F: [u8; 8] = [0x2A, 0x62, 0x10, 0x1F, 0x25, 0x22, 0x74, 0x96];
+
+/// Response of a device lacking the register, PEC is invalid
+const FRAME_INVALID: [u8; 8] = [0xFF; 8];
+
+#[test]
+fn test_start_conv_cells_max_timing() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0110_0000, 0xf4, 0x6c)
+        .into_mock();
+
+    let mut chain = MixedChain::new(LTC681X::ltc6813(bus), [ChainDevice::LTC6811, ChainDevice::LTC6813]);
+
+    let timing = chain.start_conv_cells(ADCMode::Normal, false).unwrap();
+    assert_eq!(2343, timing.regular);
+    assert_eq!(3041, timing.alternative);
+}
+
+#[test]
+fn test_start_conv_gpio_max_timing() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0110_0000, 0xd3, 0xa0)
+        .into_mock();
+
+    let mut chain = MixedChain::new(LTC681X::ltc6813(bus), [ChainDevice::LTC6804, ChainDevice::LTC6811]);
+
+    let timing = chain.start_conv_gpio(ADCMode::Normal).unwrap();
+    assert_eq!(2335, timing.regular);
+    assert_eq!(3033, timing.alternative);
+}
+
+#[test]
+fn test_read_cell_voltages_skips_missing_registers() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(0x00, 0x04, 0x07, 0xC2, [&FRAME_A, &FRAME_C])
+        .expect_register_read(0x00, 0x06, 0x9A, 0x94, [&FRAME_B, &FRAME_D])
+        .expect_register_read(0x00, 0x08, 0x5E, 0x52, [&FRAME_C, &FRAME_A])
+        .expect_register_read(0x00, 0x0A, 0xC3, 0x04, [&FRAME_D, &FRAME_B])
+        .expect_register_read(0x00, 0x09, 0xD5, 0x60, [&FRAME_E, &FRAME_INVALID])
+        .expect_register_read(0x00, 0x0B, 0x48, 0x36, [&FRAME_F, &FRAME_INVALID])
+        .into_mock();
+
+    let mut chain = MixedChain::new(LTC681X::ltc6813(bus), [ChainDevice::LTC6813, ChainDevice::LTC6811]);
+    let result = chain.read_cell_voltages().unwrap();
+
+    assert_eq!(2, result.len());
+    assert_eq!(ChainDevice::LTC6813, result[0].device());
+    assert_eq!(ChainDevice::LTC6811, result[1].device());
+
+    match &result[0] {
+        ChainVoltages::LTC6813(voltages) => {
+            assert_eq!(18, voltages.len());
+            assert_eq!(ltc6813::Channel::Cell1, voltages[0].channel);
+            assert_eq!(24979, voltages[0].voltage);
+            assert_eq!(ltc6813::Channel::Cell4, voltages[3].channel);
+            assert_eq!(26333, voltages[3].voltage);
+            assert_eq!(ltc6813::Channel::Cell13, voltages[12].channel);
+            assert_eq!(25822, voltages[12].voltage);
+            assert_eq!(ltc6813::Channel::Cell18, voltages[17].channel);
+            assert_eq!(8741, voltages[17].voltage);
+        }
+        _ => panic!("Unexpected device type"),
+    }
+
+    match &result[1] {
+        ChainVoltages::LTC6811(voltages) => {
+            assert_eq!(12, voltages.len());
+            assert_eq!(ltc6811::Channel::Cell1, voltages[0].channel);
+            assert_eq!(25441, voltages[0].voltage);
+            assert_eq!(ltc6811::Channel::Cell4, voltages[3].channel);
+            assert_eq!(25012, voltages[3].voltage);
+            assert_eq!(ltc6811::Channel::Cell7, voltages[6].channel);
+            assert_eq!(24979, voltages[6].voltage);
+            assert_eq!(ltc6811::Channel::Cell12, voltages[11].channel);
+            assert_eq!(7330, voltages[11].voltage);
+        }
+        _ => panic!("Unexpected device type"),
+    }
+}
+
+#[test]
+fn test_read_cell_voltages_only_union_registers() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(0x00, 0x04, 0x07, 0xC2, [&FRAME_A, &FRAME_B])
+        .expect_register_read(0x00, 0x06, 0x9A, 0x94, [&FRAME_B, &FRAME_A])
+        .expect_register_read(0x00, 0x08, 0x5E, 0x52, [&FRAME_C, &FRAME_INVALID])
+        .expect_register_read(0x00, 0x0A, 0xC3, 0x04, [&FRAME_D, &FRAME_INVALID])
+        .into_mock();
+
+    let mut chain = MixedChain::new(LTC681X::ltc6813(bus), [ChainDevice::LTC6811, ChainDevice::LTC6810]);
+    let result = chain.read_cell_voltages().unwrap();
+
+    match &result[1] {
+        ChainVoltages::LTC6810(voltages) => {
+            assert_eq!(6, voltages.len());
+            assert_eq!(ltc6810::Channel::Cell1, voltages[0].channel);
+            assert_eq!(26333, voltages[0].voltage);
+            assert_eq!(ltc6810::Channel::Cell6, voltages[5].channel);
+            assert_eq!(8878, voltages[5].voltage);
+        }
+        _ => panic!("Unexpected device type"),
+    }
+}
+
+#[test]
+fn test_read_cell_voltages_checksum_mismatch() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(0x00, 0x04, 0x07, 0xC2, [&FRAME_A, &FRAME_INVALID])
+        .into_mock();
+
+    let mut chain = MixedChain::new(LTC681X::ltc6813(bus), [ChainDevice::LTC6813, ChainDevice::LTC6811]);
+
+    match chain.read_cell_voltages() {
+        Err(Error::ChecksumMismatch) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_read_gpio_voltages_skips_missing_registers() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(0x00, 0x0C, 0xEF, 0xCC, [&FRAME_A, &FRAME_B])
+        .expect_register_read(0x00, 0x0E, 0x72, 0x9A, [&FRAME_B, &FRAME_C])
+        .expect_register_read(0x00, 0x0D, 0x64, 0xFE, [&FRAME_C, &FRAME_INVALID])
+        .expect_register_read(0x00, 0x0F, 0xF9, 0xA8, [&FRAME_D, &FRAME_INVALID])
+        .into_mock();
+
+    let mut chain = MixedChain::new(LTC681X::ltc6813(bus), [ChainDevice::LTC6813, ChainDevice::LTC6810]);
+    let result = chain.read_gpio_voltages().unwrap();
+
+    match &result[0] {
+        ChainVoltages::LTC6813(voltages) => {
+            assert_eq!(10, voltages.len());
+            assert_eq!(ltc6813::Channel::GPIO1, voltages[0].channel);
+            assert_eq!(24979, voltages[0].voltage);
+            assert_eq!(ltc6813::Channel::GPIO9, voltages[7].channel);
+            assert_eq!(25012, voltages[7].voltage);
+        }
+        _ => panic!("Unexpected device type"),
+    }
+
+    match &result[1] {
+        ChainVoltages::LTC6810(voltages) => {
+            assert_eq!(6, voltages.len());
+            assert_eq!(ltc6810::Channel::S0, voltages[0].channel);
+            assert_eq!(26333, voltages[0].voltage);
+            assert_eq!(ltc6810::Channel::GPIO3, voltages[3].channel);
+            assert_eq!(25441, voltages[3].voltage);
+        }
+        _ => panic!("Unexpected device type"),
+    }
+}
+
+#[test]
+fn test_write_configuration_zero_fills_missing_register_b() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[
+            &[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+            &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+        ])
+        .expect_register_write(&[
+            &[0x00, 0x24, 0xB1, 0x9E, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+            &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12],
+        ])
+        .into_mock();
+
+    let mut chain = MixedChain::new(LTC681X::ltc6813(bus), [ChainDevice::LTC6813, ChainDevice::LTC6810]);
+
+    chain
+        .write_configuration([
+            Configuration::<LTC6813>::default().into(),
+            Configuration::<LTC6810>::default().into(),
+        ])
+        .unwrap();
+}
+
+#[test]
+fn test_write_configuration_without_register_b() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[
+            &[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+            &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+        ])
+        .into_mock();
+
+    let mut chain = MixedChain::new(LTC681X::ltc6813(bus), [ChainDevice::LTC6810, ChainDevice::LTC6804]);
+
+    chain
+        .write_configuration([
+            Configuration::<LTC6810>::default().into(),
+            Configuration::<crate::ltc6804::LTC6804>::default().into(),
+        ])
+        .unwrap();
+}
+
+#[test]
+fn test_write_configuration_ltc6811_without_register_b() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[
+            &[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+            &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+        ])
+        .expect_register_write(&[
+            &[0x00, 0x24, 0xB1, 0x9E, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+            &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12],
+        ])
+        .into_mock();
+
+    let mut chain = MixedChain::new(LTC681X::ltc6813(bus), [ChainDevice::LTC6813, ChainDevice::LTC6811]);
+
+    chain
+        .write_configuration([
+            Configuration::<LTC6813>::default().into(),
+            Configuration::<LTC6811>::default().into(),
+        ])
+        .unwrap();
+}
+
+#[test]
+fn test_write_configuration_device_mismatch() {
+    let bus = DeviceMockBuilder::new().into_mock();
+    let mut chain = MixedChain::new(LTC681X::ltc6813(bus), [ChainDevice::LTC6813, ChainDevice::LTC6811]);
+
+    let result = chain.write_configuration([
+        Configuration::<LTC6813>::default().into(),
+        Configuration::<LTC6813>::default().into(),
+    ]);
+
+    match result.unwrap_err() {
+        Error::DeviceMismatch(1) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_read_configuration_skips_missing_register_b() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x02,
+            0x2B,
+            0x0A,
+            [
+                &[0xFC, 0x52, 0x17, 0xA4, 0x04, 0x20, 0x0D, 0x9A],
+                &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+            ],
+        )
+        .expect_register_read(
+            0x00,
+            0x26,
+            0x2C,
+            0xC8,
+            [&[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68], &FRAME_INVALID],
+        )
+        .into_mock();
+
+    let mut chain = MixedChain::new(LTC681X::ltc6813(bus), [ChainDevice::LTC6813, ChainDevice::LTC6810]);
+    let result = chain.read_configuration().unwrap();
+
+    let mut expected: Configuration<LTC6813> = Configuration::default();
+    expected.register_a = [0xFC, 0x52, 0x17, 0xA4, 0x04, 0x20];
+
+    assert_eq!(ChainConfiguration::LTC6813(expected), result[0]);
+    assert_eq!(ChainConfiguration::LTC6810(Configuration::default()), result[1]);
+}
+
+#[test]
+fn test_read_configuration_ltc6811_skips_register_b() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x02,
+            0x2B,
+            0x0A,
+            [
+                &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+                &[0xFC, 0x52, 0x17, 0xA4, 0x04, 0x20, 0x0D, 0x9A],
+            ],
+        )
+        .expect_register_read(
+            0x00,
+            0x26,
+            0x2C,
+            0xC8,
+            [&[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68], &FRAME_INVALID],
+        )
+        .into_mock();
+
+    let mut chain = MixedChain::new(LTC681X::ltc6813(bus), [ChainDevice::LTC6813, ChainDevice::LTC6811]);
+    let result = chain.read_configuration().unwrap();
+
+    let mut expected: Configuration<LTC6811> = Configuration::default();
+    expected.register_a = [0xFC, 0x52, 0x17, 0xA4, 0x04, 0x20];
+
+    assert_eq!(ChainConfiguration::LTC6813(Configuration::default()), result[0]);
+    assert_eq!(ChainConfiguration::LTC6811(expected), result[1]);
+}
+
+#[test]
+fn test_configuration_device() {
+    let config: ChainConfiguration = Configuration::<LTC6811>::default().into();
+    assert_eq!(ChainDevice::LTC6811, config.device());
+}
@@ -0,0 +1,337 @@
+//! Tests for I2C/SPI communication via COMM register
+use crate::comm::{Error, I2CMaster, SPIMaster};
+use crate::ltc6813::LTC6813;
+use crate::mocks::{BusError, DeviceMockBuilder, MockDelay};
+use crate::monitor::{LTC681XClient, LTC681X};
+use embedded_hal::i2c::{Error as _, ErrorKind, I2c, NoAcknowledgeSource};
+use embedded_hal::spi::{Operation, SpiDevice};
+
+#[test]
+fn test_start_comm() {
+    let bus = DeviceMockBuilder::new().expect_start_comm().into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    client.start_comm().unwrap();
+}
+
+#[test]
+fn test_read_comm() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x07,
+            0x22,
+            0x32,
+            0xD6,
+            [&[0x6A, 0x07, 0x00, 0x17, 0x00, 0x21, 0x92, 0x9E]],
+        )
+        .into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = client.read_comm().unwrap();
+    assert_eq!([0x6A, 0x07, 0x00, 0x17, 0x00, 0x21], result[0]);
+}
+
+#[test]
+fn test_i2c_write() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[&[0x07, 0x21, 0x24, 0xB2, 0x6A, 0x08, 0x00, 0x18, 0x00, 0x29, 0x41, 0x8A]])
+        .expect_start_comm()
+        .expect_register_read(
+            0x07,
+            0x22,
+            0x32,
+            0xD6,
+            [&[0x6A, 0x07, 0x00, 0x17, 0x00, 0x21, 0x92, 0x9E]],
+        )
+        .into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut i2c = I2CMaster::new(&mut client, 0).unwrap();
+    i2c.write(0x50, &[0x01, 0x02]).unwrap();
+}
+
+#[test]
+fn test_i2c_write_read_multiple_devices() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[
+            &[0x07, 0x21, 0x24, 0xB2, 0x7F, 0xF9, 0x7F, 0xF9, 0x7F, 0xF9, 0x0E, 0x80],
+            &[0x6A, 0x08, 0x01, 0x08, 0x6A, 0x18, 0x55, 0xBE],
+        ])
+        .expect_start_comm()
+        .expect_register_read(
+            0x07,
+            0x22,
+            0x32,
+            0xD6,
+            [
+                &[0x7F, 0xFF, 0x7F, 0xFF, 0x7F, 0xFF, 0xC2, 0x58],
+                &[0x6A, 0x07, 0x01, 0x07, 0x6A, 0x17, 0x21, 0x50],
+            ],
+        )
+        .expect_register_write(&[
+            &[0x07, 0x21, 0x24, 0xB2, 0x7F, 0xF9, 0x7F, 0xF9, 0x7F, 0xF9, 0x0E, 0x80],
+            &[0x0F, 0xF0, 0x0F, 0xF9, 0x7F, 0xF9, 0xD6, 0x98],
+        ])
+        .expect_start_comm()
+        .expect_register_read(
+            0x07,
+            0x22,
+            0x32,
+            0xD6,
+            [
+                &[0x7F, 0xFF, 0x7F, 0xFF, 0x7F, 0xFF, 0xC2, 0x58],
+                &[0x01, 0x20, 0x03, 0x49, 0x7F, 0xFF, 0xE3, 0xAA],
+            ],
+        )
+        .into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 2> = LTC681X::ltc6813(bus);
+
+    let mut i2c = I2CMaster::new(&mut client, 1).unwrap();
+    let mut buffer = [0x0; 2];
+    i2c.write_read(0x50, &[0x10], &mut buffer).unwrap();
+
+    assert_eq!([0x12, 0x34], buffer);
+}
+
+#[test]
+fn test_i2c_empty_transaction() {
+    let bus = DeviceMockBuilder::new().into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut i2c = I2CMaster::new(&mut client, 0).unwrap();
+    i2c.transaction(0x50, &mut []).unwrap();
+}
+
+#[test]
+fn test_i2c_address_nack() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[&[0x07, 0x21, 0x24, 0xB2, 0x6A, 0x08, 0x00, 0x19, 0x7F, 0xF9, 0x88, 0x06]])
+        .expect_start_comm()
+        .expect_register_read(
+            0x07,
+            0x22,
+            0x32,
+            0xD6,
+            [&[0x6A, 0x0F, 0x00, 0x19, 0x7F, 0xFF, 0x56, 0xA8]],
+        )
+        .into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut i2c = I2CMaster::new(&mut client, 0).unwrap();
+    let error = i2c.write(0x50, &[0x01]).unwrap_err();
+
+    assert_eq!(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address), error.kind());
+}
+
+#[test]
+fn test_i2c_data_nack_releases_bus() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[&[0x07, 0x21, 0x24, 0xB2, 0x6A, 0x08, 0x00, 0x18, 0x00, 0x28, 0xCA, 0xB8]])
+        .expect_start_comm()
+        .expect_register_read(
+            0x07,
+            0x22,
+            0x32,
+            0xD6,
+            [&[0x6A, 0x07, 0x00, 0x17, 0x00, 0x2F, 0x56, 0x58]],
+        )
+        .expect_register_write(&[&[0x07, 0x21, 0x24, 0xB2, 0x1F, 0xF9, 0x7F, 0xF9, 0x7F, 0xF9, 0x7B, 0x8E]])
+        .expect_start_comm()
+        .into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut i2c = I2CMaster::new(&mut client, 0).unwrap();
+    let error = i2c.write(0x50, &[0x01, 0x02, 0x03]).unwrap_err();
+
+    assert_eq!(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data), error.kind());
+}
+
+#[test]
+fn test_i2c_checksum_mismatch() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[&[0x07, 0x21, 0x24, 0xB2, 0x6A, 0x08, 0x00, 0x18, 0x00, 0x29, 0x41, 0x8A]])
+        .expect_start_comm()
+        .expect_register_read(
+            0x07,
+            0x22,
+            0x32,
+            0xD6,
+            [&[0x6A, 0x07, 0x00, 0x17, 0x00, 0x21, 0x92, 0x9F]],
+        )
+        .into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut i2c = I2CMaster::new(&mut client, 0).unwrap();
+    match i2c.write(0x50, &[0x01, 0x02]).unwrap_err() {
+        Error::ClientError(crate::monitor::Error::ChecksumMismatch) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_i2c_bus_error() {
+    let mut bus = DeviceMockBuilder::new().into_mock();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut i2c = I2CMaster::new(&mut client, 0).unwrap();
+    let error = i2c.write(0x50, &[0x01]).unwrap_err();
+
+    assert_eq!(ErrorKind::Other, error.kind());
+    match error {
+        Error::ClientError(crate::monitor::Error::BusError(_)) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_i2c_device_index_out_of_bounds() {
+    let bus = DeviceMockBuilder::new().into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 2> = LTC681X::ltc6813(bus);
+
+    match I2CMaster::new(&mut client, 2) {
+        Err(Error::InvalidDevice(2)) => {}
+        _ => panic!("Unexpected result"),
+    }
+}
+
+#[test]
+fn test_spi_write_multiple_cycles() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[&[0x07, 0x21, 0x24, 0xB2, 0x80, 0x10, 0x80, 0x20, 0x80, 0x30, 0x28, 0x5A]])
+        .expect_start_comm()
+        .expect_register_write(&[&[0x07, 0x21, 0x24, 0xB2, 0x80, 0x49, 0xFF, 0xF9, 0xFF, 0xF9, 0xA8, 0xAA]])
+        .expect_start_comm()
+        .into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut spi = SPIMaster::new(&mut client, 0, MockDelay::new()).unwrap();
+    spi.write(&[0x01, 0x02, 0x03, 0x04]).unwrap();
+}
+
+#[test]
+fn test_spi_transfer_multiple_devices() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[
+            &[0x07, 0x21, 0x24, 0xB2, 0x8A, 0x50, 0x85, 0xA9, 0xFF, 0xF9, 0xB5, 0xE2],
+            &[0xFF, 0xF9, 0xFF, 0xF9, 0xFF, 0xF9, 0xAA, 0x94],
+        ])
+        .expect_start_comm()
+        .expect_register_read(
+            0x07,
+            0x22,
+            0x32,
+            0xD6,
+            [
+                &[0x71, 0x2F, 0x73, 0x4F, 0x7F, 0xFF, 0xDB, 0xA2],
+                &[0x7F, 0xFF, 0x7F, 0xFF, 0x7F, 0xFF, 0xC2, 0x58],
+            ],
+        )
+        .into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 2> = LTC681X::ltc6813(bus);
+
+    let mut spi = SPIMaster::new(&mut client, 0, MockDelay::new()).unwrap();
+    let mut buffer = [0x0; 2];
+    spi.transfer(&mut buffer, &[0xA5, 0x5A]).unwrap();
+
+    assert_eq!([0x12, 0x34], buffer);
+}
+
+#[test]
+fn test_spi_transfer_in_place_multiple_cycles() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[&[0x07, 0x21, 0x24, 0xB2, 0x80, 0x10, 0x80, 0x20, 0x80, 0x30, 0x28, 0x5A]])
+        .expect_start_comm()
+        .expect_register_read(
+            0x07,
+            0x22,
+            0x32,
+            0xD6,
+            [&[0x71, 0x1F, 0x72, 0x2F, 0x73, 0x3F, 0x64, 0x26]],
+        )
+        .expect_register_write(&[&[0x07, 0x21, 0x24, 0xB2, 0x80, 0x49, 0xFF, 0xF9, 0xFF, 0xF9, 0xA8, 0xAA]])
+        .expect_start_comm()
+        .expect_register_read(
+            0x07,
+            0x22,
+            0x32,
+            0xD6,
+            [&[0x74, 0x4F, 0x7F, 0xFF, 0x7F, 0xFF, 0x97, 0xEC]],
+        )
+        .into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut spi = SPIMaster::new(&mut client, 0, MockDelay::new()).unwrap();
+    let mut buffer = [0x01, 0x02, 0x03, 0x04];
+    spi.transfer_in_place(&mut buffer).unwrap();
+
+    assert_eq!([0x11, 0x22, 0x33, 0x44], buffer);
+}
+
+#[test]
+fn test_spi_delay_splits_cycle() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[&[0x07, 0x21, 0x24, 0xB2, 0x80, 0x10, 0xFF, 0xF9, 0xFF, 0xF9, 0x9E, 0xB4]])
+        .expect_start_comm()
+        .expect_register_write(&[&[0x07, 0x21, 0x24, 0xB2, 0x8F, 0xF9, 0xFF, 0xF9, 0xFF, 0xF9, 0x0C, 0x7E]])
+        .expect_start_comm()
+        .expect_register_read(
+            0x07,
+            0x22,
+            0x32,
+            0xD6,
+            [&[0x75, 0x6F, 0x7F, 0xFF, 0x7F, 0xFF, 0x69, 0xA6]],
+        )
+        .into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).returning(|ns| assert_eq!(1_000, ns));
+
+    let mut spi = SPIMaster::new(&mut client, 0, delay).unwrap();
+    let mut buffer = [0x0];
+    spi.transaction(&mut [
+        Operation::Write(&[0x01]),
+        Operation::DelayNs(1_000),
+        Operation::Read(&mut buffer),
+    ])
+    .unwrap();
+
+    assert_eq!([0x56], buffer);
+}
+
+#[test]
+fn test_spi_empty_transaction() {
+    let bus = DeviceMockBuilder::new().into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).returning(|ns| assert_eq!(500, ns));
+
+    let mut spi = SPIMaster::new(&mut client, 0, delay).unwrap();
+    spi.transaction(&mut [Operation::Write(&[]), Operation::DelayNs(500)]).unwrap();
+}
+
+#[test]
+fn test_spi_bus_error() {
+    let mut bus = DeviceMockBuilder::new().into_mock();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut spi = SPIMaster::new(&mut client, 0, MockDelay::new()).unwrap();
+    match spi.write(&[0x01]).unwrap_err() {
+        Error::ClientError(crate::monitor::Error::BusError(_)) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_spi_device_index_out_of_bounds() {
+    let bus = DeviceMockBuilder::new().into_mock();
+    let mut client: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    match SPIMaster::new(&mut client, 1, MockDelay::new()) {
+        Err(Error::InvalidDevice(1)) => {}
+        _ => panic!("Unexpected result"),
+    }
+}
@@ -1,10 +1,15 @@
 //! Tests for static configuration of each device
+use crate::adbms1818::ADBMS1818;
+use crate::commands::{CMD_R_CONF_A, CMD_W_CONF_A};
+use crate::ltc6804::LTC6804;
 use crate::ltc6810::LTC6810;
 use crate::ltc6811::LTC6811;
 use crate::ltc6812::LTC6812;
 use crate::ltc6813::LTC6813;
-use crate::monitor::{ChannelIndex, ChannelType, DeviceTypes, GroupedRegisterIndex, RegisterAddress, RegisterLocator};
-use crate::{ltc6810, ltc6811, ltc6812, ltc6813};
+use crate::monitor::{
+    ChannelIndex, ChannelType, DeviceTypes, GroupedRegisterIndex, RegisterAddress, RegisterLocator, ToFullCommand,
+};
+use crate::{adbms1818, ltc6804, ltc6810, ltc6811, ltc6812, ltc6813};
 use alloc::vec;
 use alloc::vec::Vec;
 
@@ -29,6 +34,27 @@ fn test_ltc6813_grouped_index() {
     assert_eq!(vec! {0, 1, 2, 3, 4, 5}, cells);
 }
 
+#[test]
+fn test_adbms1818_grouped_index() {
+    let mut aux = vec![];
+    let mut cells = vec![];
+
+    aux.push(adbms1818::Register::AuxiliaryA.to_index());
+    aux.push(adbms1818::Register::AuxiliaryB.to_index());
+    aux.push(adbms1818::Register::AuxiliaryC.to_index());
+    aux.push(adbms1818::Register::AuxiliaryD.to_index());
+
+    cells.push(adbms1818::Register::CellVoltageA.to_index());
+    cells.push(adbms1818::Register::CellVoltageB.to_index());
+    cells.push(adbms1818::Register::CellVoltageC.to_index());
+    cells.push(adbms1818::Register::CellVoltageD.to_index());
+    cells.push(adbms1818::Register::CellVoltageE.to_index());
+    cells.push(adbms1818::Register::CellVoltageF.to_index());
+
+    assert_eq!(vec! {0, 1, 2, 3}, aux);
+    assert_eq!(vec! {0, 1, 2, 3, 4, 5}, cells);
+}
+
 #[test]
 fn test_ltc6812_grouped_index() {
     let mut aux = vec![];
@@ -66,6 +92,31 @@ fn test_ltc6811_grouped_index() {
     assert_eq!(vec! {0, 1, 2, 3}, cells);
 }
 
+#[test]
+fn test_ltc6811_configuration_registers() {
+    assert_eq!(CMD_R_CONF_A, LTC6811::REG_CONF_A.to_read_command());
+    assert_eq!(CMD_W_CONF_A, LTC6811::REG_CONF_A.to_write_command().unwrap());
+    assert_eq!(0, LTC6811::REG_CONF_A.to_index());
+    assert!(LTC6811::REG_CONF_B.is_none());
+}
+
+#[test]
+fn test_ltc6804_grouped_index() {
+    let mut aux = vec![];
+    let mut cells = vec![];
+
+    aux.push(ltc6804::Register::AuxiliaryA.to_index());
+    aux.push(ltc6804::Register::AuxiliaryB.to_index());
+
+    cells.push(ltc6804::Register::CellVoltageA.to_index());
+    cells.push(ltc6804::Register::CellVoltageB.to_index());
+    cells.push(ltc6804::Register::CellVoltageC.to_index());
+    cells.push(ltc6804::Register::CellVoltageD.to_index());
+
+    assert_eq!(vec! {0, 1}, aux);
+    assert_eq!(vec! {0, 1, 2, 3}, cells);
+}
+
 #[test]
 fn test_ltc6810_grouped_index() {
     let mut aux = vec![];
@@ -110,6 +161,35 @@ fn test_ltc6813_cell_register_locations_all() {
     assert_cell_register_locations(locations.collect());
 }
 
+#[test]
+fn test_adbms1818_cell_register_locations_all() {
+    let locations = adbms1818::CellSelection::All.get_locations();
+    assert_cell_channel_mappings(
+        adbms1818::CellSelection::All.get_locations().collect(),
+        vec![
+            adbms1818::Channel::Cell1,
+            adbms1818::Channel::Cell2,
+            adbms1818::Channel::Cell3,
+            adbms1818::Channel::Cell4,
+            adbms1818::Channel::Cell5,
+            adbms1818::Channel::Cell6,
+            adbms1818::Channel::Cell7,
+            adbms1818::Channel::Cell8,
+            adbms1818::Channel::Cell9,
+            adbms1818::Channel::Cell10,
+            adbms1818::Channel::Cell11,
+            adbms1818::Channel::Cell12,
+            adbms1818::Channel::Cell13,
+            adbms1818::Channel::Cell14,
+            adbms1818::Channel::Cell15,
+            adbms1818::Channel::Cell16,
+            adbms1818::Channel::Cell17,
+            adbms1818::Channel::Cell18,
+        ],
+    );
+    assert_cell_register_locations(locations.collect());
+}
+
 #[test]
 fn test_ltc6813_cell_register_locations_groups() {
     let locations = ltc6813::CellSelection::Group1
@@ -130,6 +210,26 @@ fn test_ltc6813_cell_register_locations_groups() {
     assert_eq!(3, ltc6813::CellSelection::Group6.get_locations().len());
 }
 
+#[test]
+fn test_adbms1818_cell_register_locations_groups() {
+    let locations = adbms1818::CellSelection::Group1
+        .get_locations()
+        .chain(adbms1818::CellSelection::Group2.get_locations())
+        .chain(adbms1818::CellSelection::Group3.get_locations())
+        .chain(adbms1818::CellSelection::Group4.get_locations())
+        .chain(adbms1818::CellSelection::Group5.get_locations())
+        .chain(adbms1818::CellSelection::Group6.get_locations());
+
+    assert_cell_register_locations(locations.collect());
+
+    assert_eq!(3, adbms1818::CellSelection::Group1.get_locations().len());
+    assert_eq!(3, adbms1818::CellSelection::Group2.get_locations().len());
+    assert_eq!(3, adbms1818::CellSelection::Group3.get_locations().len());
+    assert_eq!(3, adbms1818::CellSelection::Group4.get_locations().len());
+    assert_eq!(3, adbms1818::CellSelection::Group5.get_locations().len());
+    assert_eq!(3, adbms1818::CellSelection::Group6.get_locations().len());
+}
+
 #[test]
 fn test_ltc6812_cell_register_locations_all() {
     let locations = ltc6812::CellSelection::All.get_locations();
@@ -197,6 +297,29 @@ fn test_ltc6811_cell_register_locations_all() {
     assert_cell_register_locations(locations.collect());
 }
 
+#[test]
+fn test_ltc6804_cell_register_locations_all() {
+    let locations = ltc6804::CellSelection::All.get_locations();
+    assert_cell_channel_mappings(
+        ltc6804::CellSelection::All.get_locations().collect(),
+        vec![
+            ltc6804::Channel::Cell1,
+            ltc6804::Channel::Cell2,
+            ltc6804::Channel::Cell3,
+            ltc6804::Channel::Cell4,
+            ltc6804::Channel::Cell5,
+            ltc6804::Channel::Cell6,
+            ltc6804::Channel::Cell7,
+            ltc6804::Channel::Cell8,
+            ltc6804::Channel::Cell9,
+            ltc6804::Channel::Cell10,
+            ltc6804::Channel::Cell11,
+            ltc6804::Channel::Cell12,
+        ],
+    );
+    assert_cell_register_locations(locations.collect());
+}
+
 #[test]
 fn test_ltc6811_cell_register_locations_groups() {
     let locations = ltc6811::CellSelection::Pair1
@@ -217,6 +340,26 @@ fn test_ltc6811_cell_register_locations_groups() {
     assert_eq!(2, ltc6811::CellSelection::Pair6.get_locations().len());
 }
 
+#[test]
+fn test_ltc6804_cell_register_locations_groups() {
+    let locations = ltc6804::CellSelection::Pair1
+        .get_locations()
+        .chain(ltc6804::CellSelection::Pair2.get_locations())
+        .chain(ltc6804::CellSelection::Pair3.get_locations())
+        .chain(ltc6804::CellSelection::Pair4.get_locations())
+        .chain(ltc6804::CellSelection::Pair5.get_locations())
+        .chain(ltc6804::CellSelection::Pair6.get_locations());
+
+    assert_cell_register_locations(locations.collect());
+
+    assert_eq!(2, ltc6804::CellSelection::Pair1.get_locations().len());
+    assert_eq!(2, ltc6804::CellSelection::Pair2.get_locations().len());
+    assert_eq!(2, ltc6804::CellSelection::Pair3.get_locations().len());
+    assert_eq!(2, ltc6804::CellSelection::Pair4.get_locations().len());
+    assert_eq!(2, ltc6804::CellSelection::Pair5.get_locations().len());
+    assert_eq!(2, ltc6804::CellSelection::Pair6.get_locations().len());
+}
+
 #[test]
 fn test_ltc6811_cell_selection_pairs() {
     assert_cell_channel_mappings(
@@ -291,6 +434,15 @@ fn test_ltc6813_gpio_register_locations_all() {
     assert_eq!(ltc6813::Channel::SecondReference, locations[9].channel);
 }
 
+#[test]
+fn test_adbms1818_gpio_register_locations_all() {
+    let locations = adbms1818::GPIOSelection::All.get_locations();
+    assert_gpio_register_locations(locations.collect());
+
+    let locations: Vec<&RegisterAddress<ADBMS1818>> = adbms1818::GPIOSelection::All.get_locations().collect();
+    assert_eq!(adbms1818::Channel::SecondReference, locations[9].channel);
+}
+
 #[test]
 fn test_ltc6813_gpio_register_locations_groups() {
     let locations = ltc6813::GPIOSelection::Group1
@@ -314,6 +466,29 @@ fn test_ltc6813_gpio_register_locations_groups() {
     assert_eq!(ltc6813::Channel::SecondReference, second_ref[0].channel);
 }
 
+#[test]
+fn test_adbms1818_gpio_register_locations_groups() {
+    let locations = adbms1818::GPIOSelection::Group1
+        .get_locations()
+        .chain(adbms1818::GPIOSelection::Group2.get_locations())
+        .chain(adbms1818::GPIOSelection::Group3.get_locations())
+        .chain(adbms1818::GPIOSelection::Group4.get_locations())
+        .chain(adbms1818::GPIOSelection::Group5.get_locations())
+        .chain(adbms1818::GPIOSelection::Group6.get_locations());
+
+    assert_gpio_register_locations(locations.collect());
+
+    assert_eq!(2, adbms1818::GPIOSelection::Group1.get_locations().len());
+    assert_eq!(2, adbms1818::GPIOSelection::Group2.get_locations().len());
+    assert_eq!(2, adbms1818::GPIOSelection::Group3.get_locations().len());
+    assert_eq!(2, adbms1818::GPIOSelection::Group4.get_locations().len());
+    assert_eq!(1, adbms1818::GPIOSelection::Group5.get_locations().len());
+
+    let second_ref: Vec<&RegisterAddress<ADBMS1818>> = adbms1818::GPIOSelection::Group6.get_locations().collect();
+    assert_eq!(1, second_ref.len());
+    assert_eq!(adbms1818::Channel::SecondReference, second_ref[0].channel);
+}
+
 #[test]
 fn test_ltc6812_gpio_register_locations_all() {
     let locations = ltc6812::GPIOSelection::All.get_locations();
@@ -355,6 +530,15 @@ fn test_ltc6811_gpio_register_locations_all() {
     assert_eq!(ltc6811::Channel::SecondReference, locations[5].channel);
 }
 
+#[test]
+fn test_ltc6804_gpio_register_locations_all() {
+    let locations = ltc6804::GPIOSelection::All.get_locations();
+    assert_gpio_register_locations(locations.collect());
+
+    let locations: Vec<&RegisterAddress<LTC6804>> = ltc6804::GPIOSelection::All.get_locations().collect();
+    assert_eq!(ltc6804::Channel::SecondReference, locations[5].channel);
+}
+
 #[test]
 fn test_ltc6811_gpio_register_locations_groups() {
     let locations = ltc6811::GPIOSelection::GPIO1
@@ -378,6 +562,29 @@ fn test_ltc6811_gpio_register_locations_groups() {
     assert_eq!(ltc6811::Channel::SecondReference, second_ref[0].channel);
 }
 
+#[test]
+fn test_ltc6804_gpio_register_locations_groups() {
+    let locations = ltc6804::GPIOSelection::GPIO1
+        .get_locations()
+        .chain(ltc6804::GPIOSelection::GPIO2.get_locations())
+        .chain(ltc6804::GPIOSelection::GPIO3.get_locations())
+        .chain(ltc6804::GPIOSelection::GPIO4.get_locations())
+        .chain(ltc6804::GPIOSelection::GPIO5.get_locations())
+        .chain(ltc6804::GPIOSelection::SecondReference.get_locations());
+
+    assert_gpio_register_locations(locations.collect());
+
+    assert_eq!(1, ltc6804::GPIOSelection::GPIO1.get_locations().len());
+    assert_eq!(1, ltc6804::GPIOSelection::GPIO2.get_locations().len());
+    assert_eq!(1, ltc6804::GPIOSelection::GPIO3.get_locations().len());
+    assert_eq!(1, ltc6804::GPIOSelection::GPIO4.get_locations().len());
+    assert_eq!(1, ltc6804::GPIOSelection::GPIO5.get_locations().len());
+
+    let second_ref: Vec<&RegisterAddress<LTC6804>> = ltc6804::GPIOSelection::SecondReference.get_locations().collect();
+    assert_eq!(1, second_ref.len());
+    assert_eq!(ltc6804::Channel::SecondReference, second_ref[0].channel);
+}
+
 /// Array representation of correct GPIO register locations
 const LTC6810_CORRECT_GPIO_LOCATIONS: [[usize; 3]; 4] = [[0, 0, 1], [1, 0, 2], [2, 1, 0], [3, 1, 1]];
 
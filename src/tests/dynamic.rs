@@ -0,0 +1,413 @@
+//! Tests for daisy chains with a chain length chosen at runtime
+use crate::config::Configuration;
+use crate::dynamic::DynamicLTC681X;
+use crate::ltc6810::LTC6810;
+use crate::ltc6813::{CellSelection, Channel, Register, LTC6813};
+use crate::mocks::{DeviceMockBuilder, MockDelay};
+use crate::monitor::{ADCMode, Error, LTC681XClient, SelfTest, LTC681X};
+
+#[test]
+fn test_read_register_connected_devices_only() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x04,
+            0x07,
+            0xC2,
+            [
+                &[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C],
+                &[0xDD, 0x66, 0x72, 0x1D, 0xA2, 0x1C, 0x11, 0x94],
+            ],
+        )
+        .into_mock();
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+
+    let result = chain.read_register(Register::CellVoltageA).unwrap();
+    assert_eq!(2, result.len());
+    assert_eq!([24979, 7867, 8878], result[0]);
+    assert_eq!([26333, 7538, 7330], result[1]);
+}
+
+#[test]
+fn test_read_register_pec_error() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x04,
+            0x07,
+            0xC2,
+            [
+                &[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C],
+                &[0xDD, 0x66, 0x72, 0x1D, 0xA2, 0x1C, 0x11, 0x95],
+            ],
+        )
+        .into_mock();
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+
+    match chain.read_register(Register::CellVoltageA).unwrap_err() {
+        Error::ChecksumMismatch => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_read_voltages() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x04,
+            0x07,
+            0xC2,
+            [
+                &[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C],
+                &[0xDD, 0x66, 0x72, 0x1D, 0xA2, 0x1C, 0x11, 0x94],
+                &[0x61, 0x63, 0xBD, 0x1E, 0xE4, 0x22, 0x3F, 0x42],
+            ],
+        )
+        .expect_register_read(
+            0x00,
+            0x08,
+            0x5E,
+            0x52,
+            [
+                &[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C],
+                &[0xDD, 0x66, 0x72, 0x1D, 0xA2, 0x1C, 0x11, 0x94],
+                &[0x61, 0x63, 0xBD, 0x1E, 0xE4, 0x22, 0x3F, 0x42],
+            ],
+        )
+        .expect_register_read(
+            0x00,
+            0x09,
+            0xD5,
+            0x60,
+            [
+                &[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C],
+                &[0xDD, 0x66, 0x72, 0x1D, 0xA2, 0x1C, 0x11, 0x94],
+                &[0x61, 0x63, 0xBD, 0x1E, 0xE4, 0x22, 0x3F, 0x42],
+            ],
+        )
+        .into_mock();
+
+    let client: LTC681X<_, _, LTC6813, 8> = LTC681X::ltc6813(bus);
+    let mut chain = DynamicLTC681X::new(client, 3).unwrap();
+
+    let result = chain.read_voltages(CellSelection::Group1).unwrap();
+    assert_eq!(3, result.len());
+    assert_eq!(3, result[0].len());
+
+    assert_eq!(Channel::Cell1, result[0][0].channel);
+    assert_eq!(24979, result[0][0].voltage);
+    assert_eq!(Channel::Cell1, result[1][0].channel);
+    assert_eq!(26333, result[1][0].voltage);
+    assert_eq!(Channel::Cell1, result[2][0].channel);
+    assert_eq!(25441, result[2][0].voltage);
+    assert_eq!(Channel::Cell13, result[2][2].channel);
+    assert_eq!(25441, result[2][2].voltage);
+}
+
+#[test]
+fn test_write_register_connected_devices_only() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[
+            &[0x00, 0x01, 0x3D, 0x6E, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x22, 0xEE],
+            &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12],
+        ])
+        .into_mock();
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+
+    chain
+        .write_register(
+            Register::ConfigurationA,
+            &[[0x01, 0x02, 0x03, 0x04, 0x05, 0x06], [0x0; 6]],
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_write_configuration_connected_devices_only() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[
+            &[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+            &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+        ])
+        .expect_register_write(&[
+            &[0x00, 0x24, 0xB1, 0x9E, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+            &[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+        ])
+        .into_mock();
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+
+    chain
+        .write_configuration(&[Configuration::default(), Configuration::default()])
+        .unwrap();
+}
+
+#[test]
+fn test_write_configuration_verified_connected_devices_only() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[
+            &[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+            &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+        ])
+        .expect_register_write(&[
+            &[0x00, 0x24, 0xB1, 0x9E, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+            &[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+        ])
+        .expect_register_read(
+            0x00,
+            0x02,
+            0x2B,
+            0x0A,
+            [
+                &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+                &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+            ],
+        )
+        .expect_register_read(
+            0x00,
+            0x26,
+            0x2C,
+            0xC8,
+            [
+                &[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+                &[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+            ],
+        )
+        .into_mock();
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+
+    chain
+        .write_configuration_verified(&[Configuration::default(), Configuration::default()], 0)
+        .unwrap();
+}
+
+#[test]
+fn test_read_configuration() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x02,
+            0x2B,
+            0x0A,
+            [
+                &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+                &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+            ],
+        )
+        .expect_register_read(
+            0x00,
+            0x26,
+            0x2C,
+            0xC8,
+            [
+                &[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+                &[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+            ],
+        )
+        .into_mock();
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+
+    let result = chain.read_configuration().unwrap();
+    assert_eq!(2, result.len());
+    assert_eq!(Configuration::default(), result[0]);
+    assert_eq!(Configuration::default(), result[1]);
+}
+
+#[test]
+fn test_client_status_self_test_connected_devices_only() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0010_1111, 0x7B, 0xDE)
+        .expect_register_read(
+            0x00,
+            0x10,
+            0xED,
+            0x72,
+            [
+                &[0x55, 0x95, 0x55, 0x95, 0x55, 0x95, 0x02, 0xCA],
+                &[0x55, 0x95, 0x55, 0x95, 0x55, 0x95, 0x02, 0xCA],
+            ],
+        )
+        .expect_register_read(
+            0x00,
+            0x12,
+            0x70,
+            0x24,
+            [
+                &[0x55, 0x95, 0x03, 0x1C, 0x04, 0x15, 0xB4, 0xBE],
+                &[0x55, 0x95, 0x03, 0x1C, 0x04, 0x15, 0xB4, 0xBE],
+            ],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).return_const(());
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+
+    let result = chain
+        .client()
+        .status_self_test(ADCMode::Normal, SelfTest::Test1, false, &mut delay)
+        .unwrap();
+    assert_eq!(2, result.len());
+    assert!(result[0].is_empty());
+    assert!(result[1].is_empty());
+}
+
+#[test]
+fn test_client_diagnose_mux_connected_devices_only() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0111, 0b0001_0101, 0x78, 0x5E)
+        .expect_register_read(
+            0x00,
+            0x12,
+            0x70,
+            0x24,
+            [
+                &[0xC8, 0x00, 0x00, 0x00, 0x00, 0x02, 0xDC, 0xAA],
+                &[0xC8, 0x00, 0x00, 0x00, 0x00, 0x70, 0x4B, 0x04],
+            ],
+        )
+        .expect_register_read(
+            0x00,
+            0x0F,
+            0xF9,
+            0xA8,
+            [
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12],
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12],
+            ],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).return_const(());
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+
+    let result = chain.client().diagnose_mux(&mut delay).unwrap();
+    assert_eq!(2, result.len());
+    assert!(result[0]);
+    assert!(!result[1]);
+}
+
+#[test]
+fn test_client_read_configuration_defaults_for_missing_devices() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x02,
+            0x2B,
+            0x0A,
+            [
+                &[0xFC, 0x52, 0x17, 0xA4, 0x04, 0x20, 0x0D, 0x9A],
+                &[0xFC, 0x52, 0x17, 0xA4, 0x04, 0x20, 0x0D, 0x9A],
+            ],
+        )
+        .expect_register_read(
+            0x00,
+            0x26,
+            0x2C,
+            0xC8,
+            [
+                &[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+                &[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+            ],
+        )
+        .into_mock();
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+
+    let result = chain.client().read_configuration().unwrap();
+    assert!(result[1].reference_power_enabled());
+    assert_eq!(Configuration::default(), result[2]);
+    assert_eq!(Configuration::default(), result[3]);
+}
+
+#[test]
+fn test_wake_up_connected_devices_only() {
+    let bus = DeviceMockBuilder::new()
+        .expect_wake_up_pulse()
+        .expect_wake_up_pulse()
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(2).returning(|ns| assert_eq!(400_000, ns));
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+
+    assert_eq!(2, chain.length());
+    chain.client().wake_up(&mut delay).unwrap();
+}
+
+#[test]
+fn test_client_read_serial_ids_connected_devices_only() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x2C,
+            0x59,
+            0x90,
+            [
+                &[0x78, 0x56, 0x34, 0x12, 0xCD, 0xAB, 0xB2, 0x9A],
+                &[0x01, 0x00, 0x00, 0x00, 0x00, 0x80, 0xBA, 0x6C],
+            ],
+        )
+        .into_mock();
+
+    let client: LTC681X<_, _, LTC6810, 4> = LTC681X::ltc6810(bus);
+    let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+
+    let result = chain.client().read_serial_ids().unwrap();
+    assert_eq!([0xABCD_1234_5678, 0x8000_0000_0001, 0x0, 0x0], result);
+}
+
+#[test]
+fn test_write_configuration_length_mismatch() {
+    let bus = DeviceMockBuilder::new().into_mock();
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    let mut chain = DynamicLTC681X::new(client, 2).unwrap();
+
+    match chain.write_configuration(&[Configuration::default()]).unwrap_err() {
+        Error::LengthMismatch(1) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_new_zero_length() {
+    let bus = DeviceMockBuilder::new().into_mock();
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    match DynamicLTC681X::new(client, 0) {
+        Err(Error::InvalidChainLength(0)) => {}
+        _ => panic!("Unexpected result"),
+    }
+}
+
+#[test]
+fn test_new_length_exceeds_maximum() {
+    let bus = DeviceMockBuilder::new().into_mock();
+
+    let client: LTC681X<_, _, LTC6813, 4> = LTC681X::ltc6813(bus);
+    match DynamicLTC681X::new(client, 5) {
+        Err(Error::InvalidChainLength(5)) => {}
+        _ => panic!("Unexpected result"),
+    }
+}
@@ -1,4 +1,7 @@
+mod chain;
+mod comm;
 mod device_config;
+mod dynamic;
 mod monitor;
 mod pec15;
 mod reg_config;
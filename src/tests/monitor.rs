@@ -1,12 +1,20 @@
 //! Tests for generic, device type independent, logic
-use crate::config::{Cell, Configuration, GPIO};
+use crate::adbms1818::{self, ADBMS1818};
+use crate::config::{Configuration, DischargeTimeout, PWMConfiguration, SControlConfiguration, SPinControl};
+use crate::ltc6804;
+use crate::ltc6804::LTC6804;
+use crate::ltc6810;
 use crate::ltc6810::LTC6810;
+use crate::ltc6811;
 use crate::ltc6811::LTC6811;
 use crate::ltc6812::LTC6812;
-use crate::ltc6813::{CellSelection, Channel, GPIOSelection, Register, LTC6813};
-use crate::mocks::{BusError, BusMockBuilder, DeviceMockBuilder, MockPin, MockSPIBus, MockSPIDevice, PinError};
-use crate::monitor::{ADCMode, Error, LTC681XClient, PollClient, StatusGroup, LTC681X};
+use crate::ltc6813::{Cell, CellSelection, Channel, GPIOSelection, Register, GPIO, LTC6813};
+use crate::mocks::{
+    BusError, BusMockBuilder, DeviceMockBuilder, MockDelay, MockPin, MockSPIBus, MockSPIDevice, PinError,
+};
+use crate::monitor::{ADCMode, CheckedValue, Error, LTC681XClient, PollClient, SelfTest, StatusGroup, LTC681X};
 use alloc::string::ToString;
+use embedded_hal::spi::Operation;
 
 #[test]
 fn test_start_conv_cells_acc_modes() {
@@ -246,6 +254,228 @@ fn test_start_conv_gpio_transfer_error() {
     }
 }
 
+#[test]
+fn test_start_conv_gpio_redundant() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0000_0000, 0x82, 0x76)
+        .expect_command(0b0000_0100, 0b1000_0010, 0xD3, 0x4A)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let timing = monitor.start_conv_gpio_redundant(ADCMode::Normal, GPIOSelection::All).unwrap();
+    assert_eq!(3862, timing.regular);
+    assert_eq!(5025, timing.alternative);
+
+    let timing = monitor.start_conv_gpio_redundant(ADCMode::Fast, GPIOSelection::Group2).unwrap();
+    assert_eq!(380, timing.regular);
+    assert_eq!(439, timing.alternative);
+}
+
+#[test]
+fn test_start_conv_gpio_redundant_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.start_conv_gpio_redundant(ADCMode::Normal, GPIOSelection::All);
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_start_conv_cells_gpio_acc_modes() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0110_1111, 0x9C, 0x54)
+        .expect_command(0b0000_0100, 0b1110_1111, 0x50, 0x3E)
+        .expect_command(0b0000_0101, 0b1110_1111, 0xD8, 0x72)
+        .expect_command(0b0000_0100, 0b0110_1111, 0x14, 0x18)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let timing = monitor.start_conv_cells_gpio(ADCMode::Normal, false).unwrap();
+    assert_eq!(3157, timing.regular);
+    assert_eq!(4087, timing.alternative);
+
+    let timing = monitor.start_conv_cells_gpio(ADCMode::Fast, false).unwrap();
+    assert_eq!(1527, timing.regular);
+    assert_eq!(1760, timing.alternative);
+
+    let timing = monitor.start_conv_cells_gpio(ADCMode::Filtered, false).unwrap();
+    assert_eq!(268_465, timing.regular);
+    assert_eq!(5949, timing.alternative);
+
+    let timing = monitor.start_conv_cells_gpio(ADCMode::Other, false).unwrap();
+    assert_eq!(17_120, timing.regular);
+    assert_eq!(9672, timing.alternative);
+}
+
+#[test]
+fn test_start_conv_cells_gpio_permit_charging() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0111_1111, 0xC7, 0x7A)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6811, 1> = LTC681X::ltc6811(bus);
+
+    let timing = monitor.start_conv_cells_gpio(ADCMode::Normal, true).unwrap();
+    assert_eq!(3145, timing.regular);
+    assert_eq!(4035, timing.alternative);
+}
+
+#[test]
+fn test_start_conv_cells_gpio_sdo_polling() {
+    let mut cs = MockPin::new();
+    cs.expect_set_low().times(1).returning(move || Ok(()));
+
+    let bus = BusMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0110_1111, 0x9C, 0x54)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::enable_sdo_polling(bus, cs);
+    monitor.start_conv_cells_gpio(ADCMode::Normal, false).unwrap();
+}
+
+#[test]
+fn test_start_conv_cells_gpio_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.start_conv_cells_gpio(ADCMode::Normal, false);
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_start_conv_cells_sum_acc_modes() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0110_0111, 0x74, 0x5A)
+        .expect_command(0b0000_0100, 0b1110_0111, 0xB8, 0x30)
+        .expect_command(0b0000_0101, 0b1110_0111, 0x30, 0x7C)
+        .expect_command(0b0000_0100, 0b0110_0111, 0xFC, 0x16)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let timing = monitor.start_conv_cells_sum(ADCMode::Normal, false).unwrap();
+    assert_eq!(2750, timing.regular);
+    assert_eq!(3564, timing.alternative);
+
+    let timing = monitor.start_conv_cells_sum(ADCMode::Fast, false).unwrap();
+    assert_eq!(1324, timing.regular);
+    assert_eq!(1528, timing.alternative);
+
+    let timing = monitor.start_conv_cells_sum(ADCMode::Filtered, false).unwrap();
+    assert_eq!(234_895, timing.regular);
+    assert_eq!(5193, timing.alternative);
+
+    let timing = monitor.start_conv_cells_sum(ADCMode::Other, false).unwrap();
+    assert_eq!(14_968, timing.regular);
+    assert_eq!(8451, timing.alternative);
+}
+
+#[test]
+fn test_start_conv_cells_sum_permit_charging() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0111_0111, 0x2F, 0x74)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6812, 1> = LTC681X::ltc6812(bus);
+
+    let timing = monitor.start_conv_cells_sum(ADCMode::Normal, true).unwrap();
+    assert_eq!(2363, timing.regular);
+    assert_eq!(3060, timing.alternative);
+}
+
+#[test]
+fn test_start_conv_cells_sum_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.start_conv_cells_sum(ADCMode::Normal, false);
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_start_conv_open_wire_acc_modes() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0110_1000, 0x1C, 0x62)
+        .expect_command(0b0000_0010, 0b1110_1000, 0xD0, 0x08)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let timing = monitor
+        .start_conv_open_wire(ADCMode::Normal, CellSelection::All, true, false)
+        .unwrap();
+    assert_eq!(2343, timing.regular);
+    assert_eq!(3041, timing.alternative);
+
+    let timing = monitor
+        .start_conv_open_wire(ADCMode::Fast, CellSelection::All, true, false)
+        .unwrap();
+    assert_eq!(1121, timing.regular);
+    assert_eq!(1296, timing.alternative);
+}
+
+#[test]
+fn test_start_conv_open_wire_pull_down() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0010_1000, 0xFB, 0xE8)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+    monitor
+        .start_conv_open_wire(ADCMode::Normal, CellSelection::All, false, false)
+        .unwrap();
+}
+
+#[test]
+fn test_start_conv_open_wire_dcp_and_groups() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0111_1000, 0x47, 0x4C)
+        .expect_command(0b0000_0011, 0b0110_1001, 0x97, 0x50)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+    monitor
+        .start_conv_open_wire(ADCMode::Normal, CellSelection::All, true, true)
+        .unwrap();
+
+    let timing = monitor
+        .start_conv_open_wire(ADCMode::Normal, CellSelection::Group1, true, false)
+        .unwrap();
+    assert_eq!(407, timing.regular);
+    assert_eq!(523, timing.alternative);
+}
+
+#[test]
+fn test_start_conv_open_wire_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.start_conv_open_wire(ADCMode::Normal, CellSelection::All, true, false);
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
 #[test]
 fn test_start_overlap_measurement_acc_modes() {
     let bus = DeviceMockBuilder::new()
@@ -1307,8 +1537,6 @@ fn test_write_configuration_ltc6810() {
     let mut config = Configuration::default();
     config.set_ov_comp_voltage(4_300_000).unwrap();
     config.set_uv_comp_voltage(3_000_000).unwrap();
-    config.discharge_cell(Cell::Cell13);
-    config.discharge_cell(Cell::Cell17);
 
     monitor.write_configuration([config]).unwrap();
 }
@@ -2648,3 +2876,2365 @@ fn test_read_internal_device_parameters_sdo_polling_cs_high_error() {
         _ => panic!("Unexpected error type"),
     }
 }
+
+#[test]
+fn test_detect_open_wire_all_closed() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0110_1000, 0x1C, 0x62)
+        .expect_command(0b0000_0011, 0b0110_1000, 0x1C, 0x62)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x88, 0x90, 0x92, 0x90, 0x9C, 0x90, 0xE3, 0x2C]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [&[0xA6, 0x90, 0xB0, 0x90, 0xBA, 0x90, 0xB3, 0xBE]],
+        )
+        .expect_command(0b0000_0011, 0b0010_1000, 0xFB, 0xE8)
+        .expect_command(0b0000_0011, 0b0010_1000, 0xFB, 0xE8)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x7E, 0x90, 0x88, 0x90, 0x92, 0x90, 0x7D, 0xFA]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [&[0x9C, 0x90, 0xA6, 0x90, 0xB0, 0x90, 0x70, 0xF2]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(4).returning(|ns| assert_eq!(3_026_000, ns));
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    let result = monitor.detect_open_wire(ADCMode::Normal, false, &mut delay).unwrap();
+    assert_eq!(1, result.len());
+    assert!(!result[0].c0_open);
+    assert_eq!(6, result[0].wires.len());
+
+    assert_eq!(ltc6810::Channel::Cell1, result[0].wires[0].channel);
+    assert_eq!(ltc6810::Channel::Cell6, result[0].wires[5].channel);
+
+    for wire in &result[0].wires {
+        assert!(!wire.open);
+    }
+}
+
+#[test]
+fn test_detect_open_wire_open() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0110_1000, 0x1C, 0x62)
+        .expect_command(0b0000_0011, 0b0110_1000, 0x1C, 0x62)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x00, 0x00, 0x92, 0x90, 0x30, 0x75, 0x4A, 0xC6]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [&[0xA6, 0x90, 0xB0, 0x90, 0xBA, 0x90, 0xB3, 0xBE]],
+        )
+        .expect_command(0b0000_0011, 0b0010_1000, 0xFB, 0xE8)
+        .expect_command(0b0000_0011, 0b0010_1000, 0xFB, 0xE8)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x7E, 0x90, 0x88, 0x90, 0x92, 0x90, 0x7D, 0xFA]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [&[0x9C, 0x90, 0xA6, 0x90, 0x00, 0x00, 0xAA, 0xBC]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(4).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    let result = monitor.detect_open_wire(ADCMode::Normal, false, &mut delay).unwrap();
+    assert!(result[0].c0_open);
+
+    assert!(!result[0].wires[0].open);
+    assert!(result[0].wires[1].open);
+    assert!(!result[0].wires[2].open);
+    assert!(!result[0].wires[3].open);
+    assert!(!result[0].wires[4].open);
+    assert!(result[0].wires[5].open);
+}
+
+#[test]
+fn test_detect_open_wire_multiple_devices() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0110_1000, 0x1C, 0x62)
+        .expect_command(0b0000_0011, 0b0110_1000, 0x1C, 0x62)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [
+                &[0x88, 0x90, 0x92, 0x90, 0x9C, 0x90, 0xE3, 0x2C],
+                &[0x00, 0x00, 0x92, 0x90, 0x30, 0x75, 0x4A, 0xC6],
+            ],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [
+                &[0xA6, 0x90, 0xB0, 0x90, 0xBA, 0x90, 0xB3, 0xBE],
+                &[0xA6, 0x90, 0xB0, 0x90, 0xBA, 0x90, 0xB3, 0xBE],
+            ],
+        )
+        .expect_command(0b0000_0011, 0b0010_1000, 0xFB, 0xE8)
+        .expect_command(0b0000_0011, 0b0010_1000, 0xFB, 0xE8)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [
+                &[0x7E, 0x90, 0x88, 0x90, 0x92, 0x90, 0x7D, 0xFA],
+                &[0x7E, 0x90, 0x88, 0x90, 0x92, 0x90, 0x7D, 0xFA],
+            ],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [
+                &[0x9C, 0x90, 0xA6, 0x90, 0xB0, 0x90, 0x70, 0xF2],
+                &[0x9C, 0x90, 0xA6, 0x90, 0x00, 0x00, 0xAA, 0xBC],
+            ],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(4).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6810, 2> = LTC681X::ltc6810(bus);
+
+    let result = monitor.detect_open_wire(ADCMode::Normal, false, &mut delay).unwrap();
+    assert_eq!(2, result.len());
+
+    assert!(!result[0].c0_open);
+    assert!(result[0].wires.iter().all(|wire| !wire.open));
+
+    assert!(result[1].c0_open);
+    assert!(result[1].wires[1].open);
+    assert!(result[1].wires[5].open);
+}
+
+#[test]
+fn test_detect_open_wire_sdo_polling() {
+    let mut cs = MockPin::new();
+    cs.expect_set_low().times(8).returning(|| Ok(()));
+    cs.expect_set_high().times(8).returning(|| Ok(()));
+
+    let bus = BusMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0110_1000, 0x1C, 0x62)
+        .expect_command(0b0000_0011, 0b0110_1000, 0x1C, 0x62)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            &[[0x88, 0x90, 0x92, 0x90, 0x9C, 0x90, 0xE3, 0x2C]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            &[[0xA6, 0x90, 0xB0, 0x90, 0xBA, 0x90, 0xB3, 0xBE]],
+        )
+        .expect_command(0b0000_0011, 0b0010_1000, 0xFB, 0xE8)
+        .expect_command(0b0000_0011, 0b0010_1000, 0xFB, 0xE8)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            &[[0x7E, 0x90, 0x88, 0x90, 0x92, 0x90, 0x7D, 0xFA]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            &[[0x9C, 0x90, 0xA6, 0x90, 0xB0, 0x90, 0x70, 0xF2]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(4).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::enable_sdo_polling(bus, cs);
+
+    let result = monitor.detect_open_wire(ADCMode::Normal, false, &mut delay).unwrap();
+    assert!(!result[0].c0_open);
+    assert!(result[0].wires.iter().all(|wire| !wire.open));
+}
+
+#[test]
+fn test_detect_open_wire_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut delay = MockDelay::new();
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.detect_open_wire(ADCMode::Normal, false, &mut delay);
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_cell_self_test_passed() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0010_0111, 0xB4, 0x1C)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x55, 0x95, 0x55, 0x95, 0x55, 0x95, 0x02, 0xCA]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [&[0x55, 0x95, 0x55, 0x95, 0x55, 0x95, 0x02, 0xCA]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).returning(|ns| assert_eq!(3_026_000, ns));
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    let result = monitor
+        .cell_self_test(ADCMode::Normal, SelfTest::Test1, false, &mut delay)
+        .unwrap();
+    assert_eq!(1, result.len());
+    assert!(result[0].is_empty());
+}
+
+#[test]
+fn test_cell_self_test_failed() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0010_0111, 0xB4, 0x1C)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x55, 0x95, 0x55, 0x95, 0x55, 0x95, 0x02, 0xCA]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [&[0x55, 0x95, 0x34, 0x12, 0x55, 0x95, 0x36, 0x42]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    let result = monitor
+        .cell_self_test(ADCMode::Normal, SelfTest::Test1, false, &mut delay)
+        .unwrap();
+    assert_eq!(1, result[0].len());
+    assert_eq!(ltc6810::Register::CellVoltageB, result[0][0]);
+}
+
+#[test]
+fn test_cell_self_test_fast_mode_patterns() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0010, 0b1010_0111, 0x78, 0x76)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x53, 0x95, 0x53, 0x95, 0x53, 0x95, 0x20, 0xF6]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [&[0x53, 0x95, 0x53, 0x95, 0x53, 0x95, 0x20, 0xF6]],
+        )
+        .expect_command(0b0000_0010, 0b1100_0111, 0x29, 0xA0)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x9A, 0x6A, 0x9A, 0x6A, 0x9A, 0x6A, 0x3C, 0x46]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [&[0x9A, 0x6A, 0x9A, 0x6A, 0x9A, 0x6A, 0x3C, 0x46]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(2).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    // 14kHz mode
+    let result = monitor
+        .cell_self_test(ADCMode::Fast, SelfTest::Test1, true, &mut delay)
+        .unwrap();
+    assert!(result[0].is_empty());
+
+    // 27kHz mode
+    let result = monitor
+        .cell_self_test(ADCMode::Fast, SelfTest::Test2, false, &mut delay)
+        .unwrap();
+    assert!(result[0].is_empty());
+}
+
+#[test]
+fn test_cell_self_test_multiple_devices() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0100_0111, 0xE5, 0xCA)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [
+                &[0xAA, 0x6A, 0xAA, 0x6A, 0xA0, 0x6A, 0xCE, 0xC0],
+                &[0xAA, 0x6A, 0xAA, 0x6A, 0xAA, 0x6A, 0xA6, 0x94],
+            ],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [
+                &[0xAA, 0x6A, 0xAA, 0x6A, 0xA0, 0x6A, 0xCE, 0xC0],
+                &[0xAA, 0x6A, 0xAA, 0x6A, 0xAA, 0x6A, 0xA6, 0x94],
+            ],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6810, 2> = LTC681X::ltc6810(bus);
+
+    let result = monitor
+        .cell_self_test(ADCMode::Normal, SelfTest::Test2, false, &mut delay)
+        .unwrap();
+    assert_eq!(2, result.len());
+
+    assert_eq!(2, result[0].len());
+    assert_eq!(ltc6810::Register::CellVoltageA, result[0][0]);
+    assert_eq!(ltc6810::Register::CellVoltageB, result[0][1]);
+
+    assert!(result[1].is_empty());
+}
+
+#[test]
+fn test_cell_self_test_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut delay = MockDelay::new();
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.cell_self_test(ADCMode::Normal, SelfTest::Test1, false, &mut delay);
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_aux_self_test_passed() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0010_0111, 0x93, 0xD0)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_1100,
+            0xEF,
+            0xCC,
+            [&[0x55, 0x95, 0x55, 0x95, 0x55, 0x95, 0x02, 0xCA]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_1110,
+            0x72,
+            0x9A,
+            [&[0x55, 0x95, 0x55, 0x95, 0x55, 0x95, 0x02, 0xCA]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).returning(|ns| assert_eq!(3_023_000, ns));
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    let result = monitor
+        .aux_self_test(ADCMode::Normal, SelfTest::Test1, false, &mut delay)
+        .unwrap();
+    assert!(result[0].is_empty());
+}
+
+#[test]
+fn test_aux_self_test_failed() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0100_0111, 0xC2, 0x06)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_1100,
+            0xEF,
+            0xCC,
+            [&[0x55, 0x95, 0x55, 0x95, 0x55, 0x95, 0x02, 0xCA]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_1110,
+            0x72,
+            0x9A,
+            [&[0x55, 0x95, 0x55, 0x95, 0x55, 0x95, 0x02, 0xCA]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    let result = monitor
+        .aux_self_test(ADCMode::Normal, SelfTest::Test2, false, &mut delay)
+        .unwrap();
+    assert_eq!(2, result[0].len());
+    assert_eq!(ltc6810::Register::AuxiliaryA, result[0][0]);
+    assert_eq!(ltc6810::Register::AuxiliaryB, result[0][1]);
+}
+
+#[test]
+fn test_status_self_test_passed() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0010_1111, 0x7B, 0xDE)
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0000,
+            0xED,
+            0x72,
+            [&[0x55, 0x95, 0x55, 0x95, 0x55, 0x95, 0x02, 0xCA]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0010,
+            0x70,
+            0x24,
+            [&[0x55, 0x95, 0x03, 0x1C, 0x04, 0x15, 0xB4, 0xBE]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).returning(|ns| assert_eq!(2_000_000, ns));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor
+        .status_self_test(ADCMode::Normal, SelfTest::Test1, false, &mut delay)
+        .unwrap();
+    assert!(result[0].is_empty());
+}
+
+#[test]
+fn test_status_self_test_failed() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0010_1111, 0x7B, 0xDE)
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0000,
+            0xED,
+            0x72,
+            [&[0x55, 0x95, 0x34, 0x12, 0x55, 0x95, 0x36, 0x42]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0010,
+            0x70,
+            0x24,
+            [&[0x00, 0xC8, 0x00, 0x66, 0x00, 0x1B, 0xF1, 0x40]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor
+        .status_self_test(ADCMode::Normal, SelfTest::Test1, false, &mut delay)
+        .unwrap();
+    assert_eq!(2, result[0].len());
+    assert_eq!(Register::StatusA, result[0][0]);
+    assert_eq!(Register::StatusB, result[0][1]);
+}
+
+#[test]
+fn test_status_self_test_sdo_polling() {
+    let mut cs = MockPin::new();
+    cs.expect_set_low().times(3).returning(|| Ok(()));
+    cs.expect_set_high().times(3).returning(|| Ok(()));
+
+    let bus = BusMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0100_1111, 0x2A, 0x08)
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0000,
+            0xED,
+            0x72,
+            &[[0xAA, 0x6A, 0xAA, 0x6A, 0xAA, 0x6A, 0xA6, 0x94]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0010,
+            0x70,
+            0x24,
+            &[[0xAA, 0x6A, 0xAA, 0x6A, 0xA0, 0x6A, 0xCE, 0xC0]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::enable_sdo_polling(bus, cs);
+
+    let result = monitor
+        .status_self_test(ADCMode::Normal, SelfTest::Test2, false, &mut delay)
+        .unwrap();
+    assert!(result[0].is_empty());
+}
+
+#[test]
+fn test_diagnose_mux_passed() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0111, 0b0001_0101, 0x78, 0x5E)
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0010,
+            0x70,
+            0x24,
+            [&[0xC8, 0x00, 0x00, 0x00, 0x00, 0x70, 0x4B, 0x04]],
+        )
+        .expect_register_read(
+            0x00,
+            0x0F,
+            0xF9,
+            0xA8,
+            [&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).returning(|ns| assert_eq!(4_500_000, ns));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.diagnose_mux(&mut delay).unwrap();
+    assert!(!result[0]);
+}
+
+#[test]
+fn test_diagnose_mux_failed() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0111, 0b0001_0101, 0x78, 0x5E)
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0010,
+            0x70,
+            0x24,
+            [&[0xC8, 0x00, 0x00, 0x00, 0x00, 0x72, 0xD6, 0x52]],
+        )
+        .expect_register_read(
+            0x00,
+            0x0F,
+            0xF9,
+            0xA8,
+            [&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.diagnose_mux(&mut delay).unwrap();
+    assert!(result[0]);
+}
+
+#[test]
+fn test_diagnose_mux_multiple_devices() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0111, 0b0001_0101, 0x78, 0x5E)
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0010,
+            0x70,
+            0x24,
+            [
+                &[0xC8, 0x00, 0x00, 0x00, 0x00, 0x02, 0xDC, 0xAA],
+                &[0xC8, 0x00, 0x00, 0x00, 0x00, 0x70, 0x4B, 0x04],
+            ],
+        )
+        .expect_register_read(
+            0x00,
+            0x0F,
+            0xF9,
+            0xA8,
+            [
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12],
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12],
+            ],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6813, 2> = LTC681X::ltc6813(bus);
+
+    let result = monitor.diagnose_mux(&mut delay).unwrap();
+    assert!(result[0]);
+    assert!(!result[1]);
+}
+
+#[test]
+fn test_diagnose_mux_sdo_polling() {
+    let mut cs = MockPin::new();
+    cs.expect_set_low().times(3).returning(|| Ok(()));
+    cs.expect_set_high().times(3).returning(|| Ok(()));
+
+    let bus = BusMockBuilder::new()
+        .expect_command(0b0000_0111, 0b0001_0101, 0x78, 0x5E)
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0010,
+            0x70,
+            0x24,
+            &[[0xC8, 0x00, 0x00, 0x00, 0x00, 0x72, 0xD6, 0x52]],
+        )
+        .expect_register_read(
+            0x00,
+            0x0F,
+            0xF9,
+            0xA8,
+            &[[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12]],
+        )
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::enable_sdo_polling(bus, cs);
+
+    let result = monitor.diagnose_mux(&mut delay).unwrap();
+    assert!(result[0]);
+}
+
+#[test]
+fn test_diagnose_mux_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut delay = MockDelay::new();
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.diagnose_mux(&mut delay);
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_read_status_b() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0010,
+            0x70,
+            0x24,
+            [&[0xE8, 0x80, 0x09, 0x00, 0x80, 0x71, 0xDB, 0xB6]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_1111,
+            0xF9,
+            0xA8,
+            [&[0x8A, 0x61, 0x61, 0x1F, 0x01, 0x08, 0x48, 0xA2]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.read_status_b().unwrap();
+    assert_eq!(1, result.len());
+    assert_eq!(3_300_000, result[0].digital_power);
+    assert!(result[0].thermal_shutdown);
+    assert!(!result[0].mux_fail);
+    assert_eq!(7, result[0].revision);
+
+    let flags = &result[0].cell_flags;
+    assert_eq!(18, flags.len());
+
+    for (index, flag) in flags.iter().enumerate() {
+        assert_eq!(index, flag.channel as usize);
+
+        let expected_uv = matches!(flag.channel, Channel::Cell1 | Channel::Cell13);
+        let expected_ov = matches!(flag.channel, Channel::Cell2 | Channel::Cell12 | Channel::Cell18);
+        assert_eq!(expected_uv, flag.under_voltage, "{:?}", flag.channel);
+        assert_eq!(expected_ov, flag.over_voltage, "{:?}", flag.channel);
+    }
+}
+
+#[test]
+fn test_read_status_b_multiple_devices() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0010,
+            0x70,
+            0x24,
+            [
+                &[0xE8, 0x80, 0x00, 0x00, 0x00, 0x00, 0x73, 0x2A],
+                &[0xE8, 0x80, 0x42, 0x00, 0x01, 0x32, 0xC3, 0x56],
+            ],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6811, 2> = LTC681X::ltc6811(bus);
+
+    let result = monitor.read_status_b().unwrap();
+    assert_eq!(2, result.len());
+
+    assert_eq!(12, result[0].cell_flags.len());
+    assert!(result[0]
+        .cell_flags
+        .iter()
+        .all(|flag| !flag.over_voltage && !flag.under_voltage));
+    assert!(!result[0].thermal_shutdown);
+    assert!(!result[0].mux_fail);
+    assert_eq!(0, result[0].revision);
+
+    let flags = &result[1].cell_flags;
+    assert_eq!(12, flags.len());
+    assert_eq!(ltc6811::Channel::Cell1, flags[0].channel);
+    assert!(flags[0].over_voltage);
+    assert!(!flags[0].under_voltage);
+    assert_eq!(ltc6811::Channel::Cell4, flags[3].channel);
+    assert!(!flags[3].over_voltage);
+    assert!(flags[3].under_voltage);
+    assert_eq!(ltc6811::Channel::Cell9, flags[8].channel);
+    assert!(!flags[8].over_voltage);
+    assert!(flags[8].under_voltage);
+    assert_eq!(
+        3,
+        flags.iter().filter(|flag| flag.over_voltage || flag.under_voltage).count()
+    );
+
+    assert!(!result[1].thermal_shutdown);
+    assert!(result[1].mux_fail);
+    assert_eq!(3, result[1].revision);
+}
+
+#[test]
+fn test_read_status_b_pec_error() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0010,
+            0x70,
+            0x24,
+            [&[0xE8, 0x80, 0x09, 0x00, 0x80, 0x71, 0xDB, 0xB7]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.read_status_b();
+    match result.unwrap_err() {
+        Error::ChecksumMismatch => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_read_status_b_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.read_status_b();
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_read_cells_gpio() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [
+                &[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C],
+                &[0x00, 0x60, 0x00, 0x61, 0x00, 0x62, 0x2B, 0x7C],
+            ],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [
+                &[0xDD, 0x66, 0x72, 0x1D, 0xA2, 0x1C, 0x11, 0x94],
+                &[0x00, 0x63, 0x00, 0x64, 0x00, 0x65, 0x21, 0x28],
+            ],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_1100,
+            0xEF,
+            0xCC,
+            [
+                &[0x00, 0x10, 0x00, 0x20, 0x00, 0x30, 0x8C, 0x4E],
+                &[0x00, 0x11, 0x00, 0x21, 0x00, 0x31, 0x28, 0x84],
+            ],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6810, 2> = LTC681X::ltc6810(bus);
+
+    let result = monitor.read_cells_gpio().unwrap();
+    assert_eq!(2, result.len());
+
+    assert_eq!(6, result[0].cells.len());
+    assert_eq!(ltc6810::Channel::Cell1, result[0].cells[0].channel);
+    assert_eq!(24979, result[0].cells[0].voltage);
+    assert_eq!(ltc6810::Channel::Cell6, result[0].cells[5].channel);
+    assert_eq!(7330, result[0].cells[5].voltage);
+
+    assert_eq!(2, result[0].gpio.len());
+    assert_eq!(ltc6810::Channel::GPIO1, result[0].gpio[0].channel);
+    assert_eq!(0x2000, result[0].gpio[0].voltage);
+    assert_eq!(ltc6810::Channel::GPIO2, result[0].gpio[1].channel);
+    assert_eq!(0x3000, result[0].gpio[1].voltage);
+
+    assert_eq!(ltc6810::Channel::Cell1, result[1].cells[0].channel);
+    assert_eq!(0x6000, result[1].cells[0].voltage);
+    assert_eq!(ltc6810::Channel::Cell6, result[1].cells[5].channel);
+    assert_eq!(0x6500, result[1].cells[5].voltage);
+
+    assert_eq!(ltc6810::Channel::GPIO1, result[1].gpio[0].channel);
+    assert_eq!(0x2100, result[1].gpio[0].voltage);
+    assert_eq!(ltc6810::Channel::GPIO2, result[1].gpio[1].channel);
+    assert_eq!(0x3100, result[1].gpio[1].voltage);
+}
+
+#[test]
+fn test_read_cells_gpio_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.read_cells_gpio();
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_read_cells_sum() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [
+                &[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C],
+                &[0x00, 0x60, 0x00, 0x61, 0x00, 0x62, 0x2B, 0x7C],
+            ],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [
+                &[0xDD, 0x66, 0x72, 0x1D, 0xA2, 0x1C, 0x11, 0x94],
+                &[0x00, 0x63, 0x00, 0x64, 0x00, 0x65, 0x21, 0x28],
+            ],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0000,
+            0xED,
+            0x72,
+            [
+                &[0x12, 0x62, 0xA8, 0x62, 0x00, 0x7D, 0x31, 0x8A],
+                &[0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x66, 0x82],
+            ],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6810, 2> = LTC681X::ltc6810(bus);
+
+    let result = monitor.read_cells_sum().unwrap();
+    assert_eq!(2, result.len());
+
+    assert_eq!(6, result[0].cells.len());
+    assert_eq!(ltc6810::Channel::Cell1, result[0].cells[0].channel);
+    assert_eq!(24979, result[0].cells[0].voltage);
+    assert_eq!(ltc6810::Channel::Cell4, result[0].cells[3].channel);
+    assert_eq!(26333, result[0].cells[3].voltage);
+    assert_eq!(25_106_000, result[0].total_voltage);
+
+    assert_eq!(6, result[1].cells.len());
+    assert_eq!(ltc6810::Channel::Cell2, result[1].cells[1].channel);
+    assert_eq!(0x6100, result[1].cells[1].voltage);
+    assert_eq!(4_096_000, result[1].total_voltage);
+}
+
+#[test]
+fn test_read_cells_sum_pec_error() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1D]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.read_cells_sum();
+    match result.unwrap_err() {
+        Error::ChecksumMismatch => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_measure_internal_parameters_redundant() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0101, 0b0000_1000, 0x6A, 0x78)
+        .expect_command(0b0000_0100, 0b1000_1001, 0x2D, 0x20)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let timing = monitor
+        .measure_internal_parameters_redundant(ADCMode::Normal, StatusGroup::All)
+        .unwrap();
+    assert_eq!(1_600, timing.regular);
+    assert_eq!(2_000, timing.alternative);
+
+    monitor
+        .measure_internal_parameters_redundant(ADCMode::Fast, StatusGroup::CellSum)
+        .unwrap();
+}
+
+#[test]
+fn test_measure_internal_parameters_redundant_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.measure_internal_parameters_redundant(ADCMode::Normal, StatusGroup::All);
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_read_checked_voltages_redundancy_fault() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_1100,
+            0xEF,
+            0xCC,
+            [&[0x03, 0xFF, 0xBB, 0x1E, 0x10, 0xFF, 0x14, 0x06]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_1110,
+            0x72,
+            0x9A,
+            [&[0x0F, 0xFF, 0x00, 0x20, 0xFF, 0xFE, 0xE5, 0x6A]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    let result = monitor.read_checked_voltages(ltc6810::GPIOSelection::All).unwrap();
+    assert_eq!(6, result[0].len());
+
+    assert_eq!(ltc6810::Channel::S0, result[0][0].channel);
+    assert_eq!(CheckedValue::RedundancyFault, result[0][0].voltage);
+
+    assert_eq!(ltc6810::Channel::GPIO1, result[0][1].channel);
+    assert_eq!(CheckedValue::Valid(7867), result[0][1].voltage);
+
+    assert_eq!(ltc6810::Channel::GPIO2, result[0][2].channel);
+    assert_eq!(CheckedValue::Valid(0xFF10), result[0][2].voltage);
+
+    assert_eq!(ltc6810::Channel::GPIO3, result[0][3].channel);
+    assert_eq!(CheckedValue::RedundancyFault, result[0][3].voltage);
+
+    assert_eq!(ltc6810::Channel::GPIO4, result[0][4].channel);
+    assert_eq!(CheckedValue::Valid(0x2000), result[0][4].voltage);
+
+    assert_eq!(ltc6810::Channel::SecondReference, result[0][5].channel);
+    assert_eq!(CheckedValue::Valid(0xFEFF), result[0][5].voltage);
+}
+
+#[test]
+fn test_read_checked_voltages_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.read_checked_voltages(CellSelection::All);
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_read_checked_internal_device_parameters_redundancy_fault() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0000,
+            0xED,
+            0x72,
+            [
+                &[0x00, 0xFF, 0xA8, 0x62, 0x00, 0x7D, 0x94, 0xDE],
+                &[0x12, 0x62, 0xA8, 0x62, 0x0A, 0xFF, 0x80, 0xAE],
+            ],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0010,
+            0x70,
+            0x24,
+            [
+                &[0x00, 0xC8, 0x00, 0x00, 0x00, 0x00, 0x81, 0x60],
+                &[0x05, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xD8, 0xBC],
+            ],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 2> = LTC681X::ltc6813(bus);
+
+    let result = monitor.read_checked_internal_device_parameters().unwrap();
+    assert_eq!(2, result.len());
+
+    assert_eq!(CheckedValue::RedundancyFault, result[0].total_voltage);
+    match result[0].temperature {
+        CheckedValue::Valid(temperature) => assert_eq!("56.31578", temperature.to_string()),
+        _ => panic!("Unexpected temperature value"),
+    }
+    assert_eq!(CheckedValue::Valid(3_200_000), result[0].analog_power);
+    assert_eq!(CheckedValue::Valid(5_120_000), result[0].digital_power);
+
+    assert_eq!(CheckedValue::Valid(75_318_000), result[1].total_voltage);
+    assert_eq!(CheckedValue::RedundancyFault, result[1].analog_power);
+    assert_eq!(CheckedValue::RedundancyFault, result[1].digital_power);
+}
+
+#[test]
+fn test_read_checked_internal_device_parameters_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.read_checked_internal_device_parameters();
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_clear_registers() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0111, 0b0001_0001, 0xC9, 0xC0)
+        .expect_command(0b0000_0111, 0b0001_0010, 0xDF, 0xA4)
+        .expect_command(0b0000_0111, 0b0001_0011, 0x54, 0x96)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    monitor.clear_cell_registers().unwrap();
+    monitor.clear_aux_registers().unwrap();
+    monitor.clear_status_registers().unwrap();
+}
+
+#[test]
+fn test_clear_registers_sdo_polling() {
+    let mut cs = MockPin::new();
+    cs.expect_set_low().times(1).returning(move || Ok(()));
+
+    let bus = BusMockBuilder::new()
+        .expect_command(0b0000_0111, 0b0001_0001, 0xC9, 0xC0)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::enable_sdo_polling(bus, cs);
+    monitor.clear_cell_registers().unwrap();
+}
+
+#[test]
+fn test_clear_registers_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(3).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    match monitor.clear_cell_registers().unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+
+    match monitor.clear_aux_registers().unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+
+    match monitor.clear_status_registers().unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_read_checked_voltages_not_converted() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [
+                &[0xFF, 0xFF, 0xBB, 0x1E, 0xFF, 0xFF, 0x54, 0x6A],
+                &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x66, 0x4C],
+            ],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0110,
+            0x9A,
+            0x94,
+            [
+                &[0x00, 0x10, 0x00, 0x20, 0x00, 0x30, 0x8C, 0x4E],
+                &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x66, 0x4C],
+            ],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6810, 2> = LTC681X::ltc6810(bus);
+
+    let result = monitor.read_checked_voltages(ltc6810::CellSelection::All).unwrap();
+    assert_eq!(6, result[0].len());
+
+    assert_eq!(ltc6810::Channel::Cell1, result[0][0].channel);
+    assert_eq!(CheckedValue::NotConverted, result[0][0].voltage);
+    assert_eq!(ltc6810::Channel::Cell2, result[0][1].channel);
+    assert_eq!(CheckedValue::Valid(7867), result[0][1].voltage);
+    assert_eq!(ltc6810::Channel::Cell3, result[0][2].channel);
+    assert_eq!(CheckedValue::NotConverted, result[0][2].voltage);
+    assert_eq!(ltc6810::Channel::Cell4, result[0][3].channel);
+    assert_eq!(CheckedValue::Valid(0x1000), result[0][3].voltage);
+
+    assert_eq!(6, result[1].len());
+    assert!(result[1].iter().all(|voltage| voltage.voltage == CheckedValue::NotConverted));
+}
+
+#[test]
+fn test_read_checked_internal_device_parameters_not_converted() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0000,
+            0xED,
+            0x72,
+            [&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x66, 0x4C]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0001_0010,
+            0x70,
+            0x24,
+            [&[0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xDC, 0xFC]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.read_checked_internal_device_parameters().unwrap();
+    assert_eq!(CheckedValue::NotConverted, result[0].total_voltage);
+    assert_eq!(CheckedValue::NotConverted, result[0].temperature);
+    assert_eq!(CheckedValue::NotConverted, result[0].analog_power);
+    assert_eq!(CheckedValue::NotConverted, result[0].digital_power);
+}
+
+#[test]
+fn test_pladc_polling_adc_ready() {
+    let bus = DeviceMockBuilder::new()
+        .expect_pladc_poll(0x00)
+        .expect_pladc_poll(0x0F)
+        .expect_pladc_poll(0xFF)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::enable_pladc_polling(bus);
+
+    assert!(!monitor.adc_ready().unwrap());
+    assert!(!monitor.adc_ready().unwrap());
+    assert!(monitor.adc_ready().unwrap());
+}
+
+#[test]
+fn test_pladc_polling_no_cs_latching() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0110_0000, 0xf4, 0x6c)
+        .expect_pladc_poll(0xFF)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::enable_pladc_polling(bus);
+
+    monitor.start_conv_cells(ADCMode::Normal, CellSelection::All, false).unwrap();
+    assert!(monitor.adc_ready().unwrap());
+
+    let result = monitor.read_register(Register::CellVoltageA).unwrap();
+    assert_eq!([24979, 7867, 8878], result[0]);
+}
+
+#[test]
+fn test_no_polling_adc_ready_by_pladc() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0110_0000, 0xf4, 0x6c)
+        .expect_pladc_poll(0x00)
+        .expect_pladc_poll(0xFF)
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    monitor.start_conv_cells(ADCMode::Normal, CellSelection::All, false).unwrap();
+    assert!(!monitor.adc_ready().unwrap());
+    assert!(monitor.adc_ready().unwrap());
+}
+
+#[test]
+fn test_pladc_polling_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::enable_pladc_polling(bus);
+
+    match monitor.adc_ready().unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_wake_up() {
+    let bus = DeviceMockBuilder::new()
+        .expect_wake_up_pulse()
+        .expect_wake_up_pulse()
+        .expect_wake_up_pulse()
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(3).returning(|ns| assert_eq!(400_000, ns));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 3> = LTC681X::ltc6813(bus);
+    monitor.wake_up(&mut delay).unwrap();
+}
+
+#[test]
+fn test_wake_up_idle() {
+    let bus = DeviceMockBuilder::new()
+        .expect_wake_up_pulse()
+        .expect_wake_up_pulse()
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(2).returning(|ns| assert_eq!(10_000, ns));
+
+    let mut monitor: LTC681X<_, _, LTC6811, 2> = LTC681X::ltc6811(bus);
+    monitor.wake_up_idle(&mut delay).unwrap();
+}
+
+#[test]
+fn test_wake_up_sdo_polling() {
+    let mut cs = MockPin::new();
+    cs.expect_set_low().times(2).returning(|| Ok(()));
+    cs.expect_set_high().times(2).returning(|| Ok(()));
+
+    let bus = BusMockBuilder::new()
+        .expect_register_write(&[0xFF])
+        .expect_register_write(&[0xFF])
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(2).returning(|ns| assert_eq!(400_000, ns));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 2> = LTC681X::enable_sdo_polling(bus, cs);
+    monitor.wake_up(&mut delay).unwrap();
+}
+
+#[test]
+fn test_wake_up_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut delay = MockDelay::new();
+    let mut monitor: LTC681X<_, _, LTC6813, 2> = LTC681X::ltc6813(bus);
+
+    match monitor.wake_up(&mut delay).unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_read_configuration() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0010,
+            0x2B,
+            0x0A,
+            [
+                &[0xFC, 0x52, 0x17, 0xA4, 0x04, 0x20, 0x0D, 0x9A],
+                &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+            ],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0010_0110,
+            0x2C,
+            0xC8,
+            [
+                &[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+                &[0x1E, 0x02, 0x00, 0x00, 0x00, 0x00, 0xE5, 0x46],
+            ],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 2> = LTC681X::ltc6813(bus);
+
+    let result = monitor.read_configuration().unwrap();
+
+    let mut expected = Configuration::default();
+    expected.enable_reference_power();
+    expected.set_uv_comp_voltage(3_000_000).unwrap();
+    expected.set_ov_comp_voltage(4_200_000).unwrap();
+    expected.discharge_cell(Cell::Cell3);
+    expected.set_discharge_timeout(DischargeTimeout::OneMinute);
+    assert_eq!(expected, result[0]);
+
+    assert!(result[1].gpio_pull_down_enabled(GPIO::GPIO6));
+    assert!(!result[1].gpio_pull_down_enabled(GPIO::GPIO7));
+    assert!(result[1].cell_discharged(Cell::Cell13));
+    assert!(result[1].cell_discharged(Cell::Cell18));
+    assert!(!result[1].cell_discharged(Cell::Cell17));
+}
+
+#[test]
+fn test_read_configuration_single_register() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0010,
+            0x2B,
+            0x0A,
+            [&[0xFC, 0x52, 0x17, 0xA4, 0x04, 0x20, 0x0D, 0x9A]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    let result = monitor.read_configuration().unwrap();
+    assert!(result[0].reference_power_enabled());
+    assert_eq!(3_000_000, result[0].uv_comp_voltage());
+    assert_eq!(4_200_000, result[0].ov_comp_voltage());
+    assert_eq!(Configuration::<LTC6810>::default().register_b, result[0].register_b);
+}
+
+#[test]
+fn test_read_configuration_ltc6811() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0010,
+            0x2B,
+            0x0A,
+            [
+                &[0xFC, 0x52, 0x17, 0xA4, 0x04, 0x20, 0x0D, 0x9A],
+                &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+            ],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6811, 2> = LTC681X::ltc6811(bus);
+
+    let result = monitor.read_configuration().unwrap();
+    assert!(result[0].reference_power_enabled());
+    assert_eq!(3_000_000, result[0].uv_comp_voltage());
+    assert_eq!(4_200_000, result[0].ov_comp_voltage());
+    assert_eq!(Configuration::<LTC6811>::default(), result[1]);
+}
+
+#[test]
+fn test_read_configuration_pec_error() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0010,
+            0x2B,
+            0x0A,
+            [&[0xFC, 0x52, 0x17, 0xA4, 0x04, 0x20, 0x0D, 0x9B]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    match monitor.read_configuration().unwrap_err() {
+        Error::ChecksumMismatch => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_read_configuration_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    match monitor.read_configuration().unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_write_configuration_verified() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[&[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0x04, 0x00, 0x02, 0x84]])
+        .expect_register_write(&[&[0x00, 0x24, 0xB1, 0x9E, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68]])
+        // GPIO pin states, DTEN and MUTE bits differ, but are not compared
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0010,
+            0x2B,
+            0x0A,
+            [&[0x0A, 0x00, 0x00, 0x00, 0x04, 0x00, 0x6F, 0x36]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0010_0110,
+            0x2C,
+            0xC8,
+            [&[0x05, 0x80, 0x00, 0x00, 0x00, 0x00, 0x11, 0x06]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut config = Configuration::default();
+    config.discharge_cell(Cell::Cell3);
+
+    monitor.write_configuration_verified([config], 0).unwrap();
+}
+
+#[test]
+fn test_write_configuration_verified_retry() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[&[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0x04, 0x00, 0x02, 0x84]])
+        .expect_register_write(&[&[0x00, 0x24, 0xB1, 0x9E, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68]])
+        // Discharge bit was not applied
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0010,
+            0x2B,
+            0x0A,
+            [&[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0010_0110,
+            0x2C,
+            0xC8,
+            [&[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68]],
+        )
+        .expect_register_write(&[&[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0x04, 0x00, 0x02, 0x84]])
+        .expect_register_write(&[&[0x00, 0x24, 0xB1, 0x9E, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68]])
+        // Invalid checksum
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0010,
+            0x2B,
+            0x0A,
+            [&[0xF8, 0x00, 0x00, 0x00, 0x04, 0x00, 0x02, 0x85]],
+        )
+        .expect_register_write(&[&[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0x04, 0x00, 0x02, 0x84]])
+        .expect_register_write(&[&[0x00, 0x24, 0xB1, 0x9E, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68]])
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0010,
+            0x2B,
+            0x0A,
+            [&[0xF8, 0x00, 0x00, 0x00, 0x04, 0x00, 0x02, 0x84]],
+        )
+        .expect_register_read(
+            0b0000_0000,
+            0b0010_0110,
+            0x2C,
+            0xC8,
+            [&[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let mut config = Configuration::default();
+    config.discharge_cell(Cell::Cell3);
+
+    monitor.write_configuration_verified([config], 2).unwrap();
+}
+
+#[test]
+fn test_write_configuration_verified_ltc6811_without_register_b() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[
+            &[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0x04, 0x00, 0x02, 0x84],
+            &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+        ])
+        // GPIO pin states differ, but are not compared
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0010,
+            0x2B,
+            0x0A,
+            [
+                &[0x48, 0x00, 0x00, 0x00, 0x04, 0x00, 0x4E, 0x72],
+                &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+            ],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6811, 2> = LTC681X::ltc6811(bus);
+
+    let mut config = Configuration::default();
+    config.discharge_cell(ltc6811::Cell::Cell3);
+
+    monitor
+        .write_configuration_verified([config, Configuration::default()], 2)
+        .unwrap();
+}
+
+#[test]
+fn test_write_configuration_verified_failed() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[
+            &[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+            &[0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2],
+        ])
+        .expect_register_write(&[
+            &[0x00, 0x24, 0xB1, 0x9E, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+            &[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+        ])
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0010,
+            0x2B,
+            0x0A,
+            [
+                &[0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBA, 0xA2],
+                &[0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBA, 0xA2],
+            ],
+        )
+        // Discharge of cell 13 is active on second device
+        .expect_register_read(
+            0b0000_0000,
+            0b0010_0110,
+            0x2C,
+            0xC8,
+            [
+                &[0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x68],
+                &[0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCD, 0x8C],
+            ],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 2> = LTC681X::ltc6813(bus);
+
+    let result = monitor.write_configuration_verified([Configuration::default(), Configuration::default()], 0);
+    match result.unwrap_err() {
+        Error::VerificationFailed(1) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_write_configuration_verified_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.write_configuration_verified([Configuration::default()], 3);
+    match result.unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_write_pwm() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[
+            &[0x00, 0x20, 0x00, 0x00, 0xF8, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x9D, 0x1A],
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x66, 0x4C],
+        ])
+        .expect_register_read(
+            0x00,
+            0x1E,
+            0x29,
+            0xB4,
+            [
+                &[0x00, 0x00, 0x00, 0x12, 0x34, 0x56, 0xD6, 0x6E],
+                &[0x00, 0x00, 0x00, 0xAB, 0xCD, 0xEF, 0x03, 0x6A],
+            ],
+        )
+        .expect_register_write(&[
+            &[0x00, 0x1C, 0xB4, 0xE2, 0xF4, 0xFF, 0xFF, 0x12, 0x34, 0x56, 0xB4, 0x7A],
+            &[0xFF, 0xFF, 0xFF, 0xAB, 0xCD, 0xEF, 0x4C, 0x64],
+        ])
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 2> = LTC681X::ltc6813(bus);
+
+    let mut config = PWMConfiguration::default();
+    config.set_duty_cycle(Channel::Cell1, 8).unwrap();
+    config.set_duty_cycle(Channel::Cell12, 0).unwrap();
+    config.set_duty_cycle(Channel::Cell13, 4).unwrap();
+
+    monitor.write_pwm([config, PWMConfiguration::default()]).unwrap();
+}
+
+#[test]
+fn test_read_pwm() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x22,
+            0x9D,
+            0x56,
+            [
+                &[0x21, 0x43, 0x65, 0x87, 0xA9, 0xCB, 0x4A, 0x50],
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12],
+            ],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6811, 2> = LTC681X::ltc6811(bus);
+
+    let result = monitor.read_pwm().unwrap();
+    assert_eq!(Some(0x1), result[0].duty_cycle(ltc6811::Channel::Cell1));
+    assert_eq!(Some(0x2), result[0].duty_cycle(ltc6811::Channel::Cell2));
+    assert_eq!(Some(0x7), result[0].duty_cycle(ltc6811::Channel::Cell7));
+    assert_eq!(Some(0xC), result[0].duty_cycle(ltc6811::Channel::Cell12));
+    assert_eq!(Some(0x0), result[1].duty_cycle(ltc6811::Channel::Cell1));
+    assert_eq!(Some(0x0), result[1].duty_cycle(ltc6811::Channel::Cell12));
+}
+
+#[test]
+fn test_read_pwm_psb() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x22,
+            0x9D,
+            0x56,
+            [&[0x21, 0x43, 0x65, 0x87, 0xA9, 0xCB, 0x4A, 0x50]],
+        )
+        .expect_register_read(
+            0x00,
+            0x1E,
+            0x29,
+            0xB4,
+            [&[0xFF, 0xFF, 0xFF, 0x12, 0x34, 0x56, 0x99, 0x60]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor.read_pwm().unwrap();
+    assert_eq!(Some(0x1), result[0].duty_cycle(Channel::Cell1));
+    assert_eq!(Some(0xC), result[0].duty_cycle(Channel::Cell12));
+    assert_eq!(Some(0xF), result[0].duty_cycle(Channel::Cell13));
+    assert_eq!(Some(0xF), result[0].duty_cycle(Channel::Cell18));
+}
+
+#[test]
+fn test_read_pwm_pec_error() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x22,
+            0x9D,
+            0x56,
+            [&[0x21, 0x43, 0x65, 0x87, 0xA9, 0xCB, 0x4A, 0x51]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    match monitor.read_pwm().unwrap_err() {
+        Error::ChecksumMismatch => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_write_pwm_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    match monitor.write_pwm([PWMConfiguration::default()]).unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_write_s_control() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[
+            &[0x00, 0x14, 0x5C, 0xEC, 0x83, 0x00, 0x00, 0x00, 0x00, 0x10, 0x6E, 0x3C],
+            &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12],
+        ])
+        .expect_register_read(
+            0x00,
+            0x1E,
+            0x29,
+            0xB4,
+            [
+                &[0xFF, 0xFF, 0xFF, 0x12, 0x34, 0x56, 0x99, 0x60],
+                &[0xFF, 0xFF, 0xFF, 0xAB, 0xCD, 0xEF, 0x4C, 0x64],
+            ],
+        )
+        .expect_register_write(&[
+            &[0x00, 0x1C, 0xB4, 0xE2, 0xFF, 0xFF, 0xFF, 0x08, 0x00, 0x00, 0x2B, 0xA6],
+            &[0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x8D, 0x1C],
+        ])
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 2> = LTC681X::ltc6813(bus);
+
+    let mut config = SControlConfiguration::default();
+    config.set_control(Channel::Cell13, SPinControl::Low).unwrap();
+    config.set_control(Channel::Cell1, SPinControl::Pulses(3)).unwrap();
+    config.set_control(Channel::Cell2, SPinControl::Low).unwrap();
+    config.set_control(Channel::Cell12, SPinControl::Pulses(1)).unwrap();
+
+    monitor.write_s_control([config, SControlConfiguration::default()]).unwrap();
+}
+
+#[test]
+fn test_read_s_control() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x16,
+            0xC1,
+            0xBA,
+            [
+                &[0x7F, 0x21, 0x00, 0x00, 0x00, 0x9C, 0xF3, 0xD8],
+                &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC2, 0x12],
+            ],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6811, 2> = LTC681X::ltc6811(bus);
+
+    let result = monitor.read_s_control().unwrap();
+    assert_eq!(Some(SPinControl::Low), result[0].control(ltc6811::Channel::Cell1));
+    assert_eq!(Some(SPinControl::Pulses(7)), result[0].control(ltc6811::Channel::Cell2));
+    assert_eq!(Some(SPinControl::Pulses(1)), result[0].control(ltc6811::Channel::Cell3));
+    assert_eq!(Some(SPinControl::Pulses(2)), result[0].control(ltc6811::Channel::Cell4));
+    assert_eq!(Some(SPinControl::High), result[0].control(ltc6811::Channel::Cell5));
+    assert_eq!(Some(SPinControl::Low), result[0].control(ltc6811::Channel::Cell11));
+    assert_eq!(Some(SPinControl::Low), result[0].control(ltc6811::Channel::Cell12));
+    assert_eq!(Some(SPinControl::High), result[1].control(ltc6811::Channel::Cell1));
+}
+
+#[test]
+fn test_read_s_control_pec_error() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x16,
+            0xC1,
+            0xBA,
+            [&[0x7F, 0x21, 0x00, 0x00, 0x00, 0x9C, 0xF3, 0xD9]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    match monitor.read_s_control().unwrap_err() {
+        Error::ChecksumMismatch => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_start_s_control() {
+    let bus = DeviceMockBuilder::new().expect_command(0x00, 0x19, 0x8E, 0x4E).into_mock();
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    monitor.start_s_control().unwrap();
+}
+
+#[test]
+fn test_clear_s_control() {
+    let bus = DeviceMockBuilder::new().expect_command(0x00, 0x18, 0x05, 0x7C).into_mock();
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    monitor.clear_s_control().unwrap();
+}
+
+#[test]
+fn test_s_control_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(3).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    match monitor.write_s_control([SControlConfiguration::default()]).unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+
+    match monitor.start_s_control().unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+
+    match monitor.clear_s_control().unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_mute() {
+    let bus = DeviceMockBuilder::new().expect_command(0x00, 0x28, 0xE8, 0x0E).into_mock();
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    monitor.mute().unwrap();
+}
+
+#[test]
+fn test_unmute() {
+    let bus = DeviceMockBuilder::new().expect_command(0x00, 0x29, 0x63, 0x3C).into_mock();
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    monitor.unmute().unwrap();
+}
+
+#[test]
+fn test_mute_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(2).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    match monitor.mute().unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+
+    match monitor.unmute().unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_measure_cells_muted() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0x00, 0x28, 0xE8, 0x0E)
+        .expect_command(0x03, 0x62, 0x69, 0x3A)
+        // Register A
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C]],
+        )
+        // Register C
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_1000,
+            0x5E,
+            0x52,
+            [&[0x61, 0x63, 0xBD, 0x1E, 0xE4, 0x22, 0x3F, 0x42]],
+        )
+        // Register E
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_1001,
+            0xD5,
+            0x60,
+            [&[0xDE, 0x64, 0x8F, 0x21, 0x8A, 0x21, 0x8F, 0xDA]],
+        )
+        .expect_command(0x00, 0x29, 0x63, 0x3C)
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).returning(|ns| assert_eq!(523_000, ns));
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    let result = monitor
+        .measure_cells_muted(ADCMode::Normal, CellSelection::Group2, &mut delay)
+        .unwrap();
+    assert_eq!(3, result[0].len());
+
+    assert_eq!(Channel::Cell2, result[0][0].channel);
+    assert_eq!(7867, result[0][0].voltage);
+
+    assert_eq!(Channel::Cell8, result[0][1].channel);
+    assert_eq!(7869, result[0][1].voltage);
+
+    assert_eq!(Channel::Cell14, result[0][2].channel);
+    assert_eq!(8591, result[0][2].voltage);
+}
+
+#[test]
+fn test_measure_cells_muted_unmute_on_error() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0x00, 0x28, 0xE8, 0x0E)
+        .expect_command(0x03, 0x62, 0x69, 0x3A)
+        // Register A with invalid PEC
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1D]],
+        )
+        .expect_command(0x00, 0x29, 0x63, 0x3C)
+        .into_mock();
+
+    let mut delay = MockDelay::new();
+    delay.expect_delay_ns().times(1).return_const(());
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+
+    match monitor
+        .measure_cells_muted(ADCMode::Normal, CellSelection::Group2, &mut delay)
+        .unwrap_err()
+    {
+        Error::ChecksumMismatch => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_ltc6810_read_serial_ids() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x2C,
+            0x59,
+            0x90,
+            [
+                &[0x78, 0x56, 0x34, 0x12, 0xCD, 0xAB, 0xB2, 0x9A],
+                &[0x01, 0x00, 0x00, 0x00, 0x00, 0x80, 0xBA, 0x6C],
+            ],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6810, 2> = LTC681X::ltc6810(bus);
+
+    let result = monitor.read_serial_ids().unwrap();
+    assert_eq!(0xABCD_1234_5678, result[0]);
+    assert_eq!(0x8000_0000_0001, result[1]);
+}
+
+#[test]
+fn test_ltc6810_read_serial_ids_pec_error() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x2C,
+            0x59,
+            0x90,
+            [&[0x78, 0x56, 0x34, 0x12, 0xCD, 0xAB, 0xB2, 0x9B]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    match monitor.read_serial_ids().unwrap_err() {
+        Error::ChecksumMismatch => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_ltc6810_read_serial_ids_transfer_error() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |_| Err(BusError::Error1));
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    match monitor.read_serial_ids().unwrap_err() {
+        Error::BusError(_) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_ltc6810_write_configuration_specific_bits() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[&[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0xC2, 0x09, 0x0E, 0x6A]])
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    let mut config: Configuration<LTC6810> = Configuration::default();
+    config.discharge_cell(ltc6810::Cell::Cell2);
+    config.discharge_s0();
+    config.enable_multi_calibration();
+    config.enable_redundant_s_pin_conversion();
+    config.enable_discharge_monitor();
+
+    monitor.write_configuration([config]).unwrap();
+}
+
+#[test]
+fn test_ltc6810_read_configuration_specific_bits() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0x00,
+            0x02,
+            0x2B,
+            0x0A,
+            [&[0xFC, 0x52, 0x17, 0xA4, 0x84, 0x25, 0x47, 0x22]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+
+    let result = monitor.read_configuration().unwrap();
+
+    let mut expected: Configuration<LTC6810> = Configuration::default();
+    expected.enable_reference_power();
+    expected.set_uv_comp_voltage(3_000_000).unwrap();
+    expected.set_ov_comp_voltage(4_200_000).unwrap();
+    expected.discharge_cell(ltc6810::Cell::Cell3);
+    expected.discharge_s0();
+    expected.set_discharge_timeout(DischargeTimeout::OneMinute);
+    expected.force_digital_redundancy_fail();
+    expected.enable_discharge_monitor();
+
+    assert_eq!(expected, result[0]);
+}
+
+#[test]
+fn test_addressed_start_conv_cells() {
+    let bus = DeviceMockBuilder::new().expect_command(0xAB, 0x60, 0xAE, 0x1A).into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+    monitor.set_address(Some(5)).unwrap();
+
+    monitor.start_conv_cells(ADCMode::Normal, CellSelection::All, false).unwrap();
+}
+
+#[test]
+fn test_addressed_read_register() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_read(
+            0xA8,
+            0x04,
+            0x5D,
+            0xB4,
+            [&[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+    monitor.set_address(Some(5)).unwrap();
+
+    let result = monitor.read_register(Register::CellVoltageA).unwrap();
+    assert_eq!([24979, 7867, 8878], result[0]);
+}
+
+#[test]
+fn test_addressed_write_configuration() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[&[0xA8, 0x01, 0x67, 0x18, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2]])
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6810, 1> = LTC681X::ltc6810(bus);
+    monitor.set_address(Some(5)).unwrap();
+
+    monitor.write_configuration([Configuration::default()]).unwrap();
+}
+
+#[test]
+fn test_addressed_pladc_polling() {
+    let mut bus = MockSPIDevice::new();
+    bus.expect_transaction().times(1).returning(move |operations| {
+        assert_eq!(1, operations.len());
+
+        match &mut operations[0] {
+            Operation::Transfer(buffer, command) => {
+                assert_eq!(&[0xAF, 0x14, 0xA9, 0x1A, 0xFF], command);
+                buffer[4] = 0xFF;
+            }
+            _ => panic!("Received unexpected operation type {:?}", operations[0]),
+        }
+
+        Ok(())
+    });
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::enable_pladc_polling(bus);
+    monitor.set_address(Some(5)).unwrap();
+
+    assert!(monitor.adc_ready().unwrap());
+}
+
+#[test]
+fn test_address_reset_to_broadcast() {
+    let bus = DeviceMockBuilder::new().expect_command(0x03, 0x60, 0xF4, 0x6C).into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+    monitor.set_address(Some(5)).unwrap();
+    assert_eq!(Some(5), monitor.address());
+
+    monitor.set_address(None).unwrap();
+    assert_eq!(None, monitor.address());
+
+    monitor.start_conv_cells(ADCMode::Normal, CellSelection::All, false).unwrap();
+}
+
+#[test]
+fn test_address_out_of_range() {
+    let bus = DeviceMockBuilder::new().into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6813, 1> = LTC681X::ltc6813(bus);
+    monitor.set_address(Some(5)).unwrap();
+
+    match monitor.set_address(Some(16)).unwrap_err() {
+        Error::InvalidAddress => {}
+        _ => panic!("Unexpected error type"),
+    }
+    assert_eq!(Some(5), monitor.address());
+}
+
+#[test]
+fn test_ltc6804_write_configuration() {
+    let bus = DeviceMockBuilder::new()
+        .expect_register_write(&[&[0x00, 0x01, 0x3D, 0x6E, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBE, 0xE2]])
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, LTC6804, 1> = LTC681X::ltc6804(bus);
+    monitor.write_configuration([Configuration::default()]).unwrap();
+}
+
+#[test]
+fn test_ltc6804_pwm_unsupported() {
+    let bus = DeviceMockBuilder::new().into_mock();
+    let mut monitor: LTC681X<_, _, LTC6804, 1> = LTC681X::ltc6804(bus);
+
+    match monitor.read_pwm().unwrap_err() {
+        Error::UnsupportedRegister => {}
+        _ => panic!("Unexpected error type"),
+    }
+
+    match monitor.write_pwm([PWMConfiguration::default()]).unwrap_err() {
+        Error::UnsupportedRegister => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_ltc6804_s_control_unsupported() {
+    let bus = DeviceMockBuilder::new().into_mock();
+    let mut monitor: LTC681X<_, _, LTC6804, 1> = LTC681X::ltc6804(bus);
+
+    match monitor.read_s_control().unwrap_err() {
+        Error::UnsupportedRegister => {}
+        _ => panic!("Unexpected error type"),
+    }
+
+    match monitor.write_s_control([SControlConfiguration::default()]).unwrap_err() {
+        Error::UnsupportedRegister => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_ltc6804_start_conv_cells_sum_unsupported() {
+    let bus = DeviceMockBuilder::new().into_mock();
+    let mut monitor: LTC681X<_, _, LTC6804, 1> = LTC681X::ltc6804(bus);
+
+    match monitor.start_conv_cells_sum(ADCMode::Normal, false).unwrap_err() {
+        Error::UnsupportedCommand => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_ltc6804_s_control_commands_unsupported() {
+    let bus = DeviceMockBuilder::new().into_mock();
+    let mut monitor: LTC681X<_, _, LTC6804, 1> = LTC681X::ltc6804(bus);
+
+    match monitor.start_s_control().unwrap_err() {
+        Error::UnsupportedCommand => {}
+        _ => panic!("Unexpected error type"),
+    }
+
+    match monitor.clear_s_control().unwrap_err() {
+        Error::UnsupportedCommand => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_ltc6804_mute_unsupported() {
+    let bus = DeviceMockBuilder::new().into_mock();
+    let mut monitor: LTC681X<_, _, LTC6804, 1> = LTC681X::ltc6804(bus);
+
+    match monitor.mute().unwrap_err() {
+        Error::UnsupportedCommand => {}
+        _ => panic!("Unexpected error type"),
+    }
+
+    match monitor.unmute().unwrap_err() {
+        Error::UnsupportedCommand => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_ltc6804_measure_cells_muted_unsupported() {
+    let bus = DeviceMockBuilder::new().into_mock();
+    let mut delay = MockDelay::new();
+    let mut monitor: LTC681X<_, _, LTC6804, 1> = LTC681X::ltc6804(bus);
+
+    let result = monitor.measure_cells_muted(ADCMode::Normal, ltc6804::CellSelection::All, &mut delay);
+    match result {
+        Err(Error::UnsupportedCommand) => {}
+        _ => panic!("Unexpected error type"),
+    }
+}
+
+#[test]
+fn test_adbms1818_conversion_and_read() {
+    let bus = DeviceMockBuilder::new()
+        .expect_command(0b0000_0011, 0b0110_0000, 0xf4, 0x6c)
+        .expect_register_read(
+            0b0000_0000,
+            0b0000_0100,
+            0x07,
+            0xC2,
+            [&[0x93, 0x61, 0xBB, 0x1E, 0xAE, 0x22, 0x9A, 0x1C]],
+        )
+        .into_mock();
+
+    let mut monitor: LTC681X<_, _, ADBMS1818, 1> = LTC681X::adbms1818(bus);
+
+    let timing = monitor
+        .start_conv_cells(ADCMode::Normal, adbms1818::CellSelection::All, false)
+        .unwrap();
+    assert_eq!(2343, timing.regular);
+    assert_eq!(3041, timing.alternative);
+
+    let result = monitor.read_register(adbms1818::Register::CellVoltageA).unwrap();
+    assert_eq!([24979, 7867, 8878], result[0]);
+}
@@ -1,8 +1,14 @@
-use crate::config::{Cell, Configuration, DigitalRedundancyPath, DischargeTimeout, GPIO};
+use crate::config::{
+    Configuration, DigitalRedundancyPath, DischargeTimeout, DutyCycleError, PWMConfiguration, SControlConfiguration,
+    SControlError, SPinControl,
+};
+use crate::ltc6810::{self, LTC6810};
+use crate::ltc6812::{self, LTC6812};
+use crate::ltc6813::{Cell, Channel, GPIO, LTC6813};
 
 #[test]
 fn test_enable_gpio_pull_down_gpio1() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
 
     config.enable_gpio_pull_down(GPIO::GPIO1);
     assert_eq!(0b1111_0000, config.register_a[0]);
@@ -51,7 +57,7 @@ fn test_enable_gpio_pull_down_gpio1() {
 
 #[test]
 fn test_disable_gpio_pull_down_gpio1() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
     enable_all_gpio_pull_down_a(&mut config);
     config.disable_gpio_pull_down(GPIO::GPIO1);
 
@@ -61,7 +67,7 @@ fn test_disable_gpio_pull_down_gpio1() {
 
 #[test]
 fn test_disable_gpio_pull_down_gpio2() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
     enable_all_gpio_pull_down_a(&mut config);
     config.disable_gpio_pull_down(GPIO::GPIO2);
 
@@ -71,7 +77,7 @@ fn test_disable_gpio_pull_down_gpio2() {
 
 #[test]
 fn test_disable_gpio_pull_down_gpio3() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
     enable_all_gpio_pull_down_a(&mut config);
     config.disable_gpio_pull_down(GPIO::GPIO3);
 
@@ -81,7 +87,7 @@ fn test_disable_gpio_pull_down_gpio3() {
 
 #[test]
 fn test_disable_gpio_pull_down_gpio4() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
     enable_all_gpio_pull_down_a(&mut config);
     config.disable_gpio_pull_down(GPIO::GPIO4);
 
@@ -91,7 +97,7 @@ fn test_disable_gpio_pull_down_gpio4() {
 
 #[test]
 fn test_disable_gpio_pull_down_gpio5() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
     enable_all_gpio_pull_down_a(&mut config);
     config.disable_gpio_pull_down(GPIO::GPIO5);
 
@@ -101,7 +107,7 @@ fn test_disable_gpio_pull_down_gpio5() {
 
 #[test]
 fn test_disable_gpio_pull_down_gpio6() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
     enable_all_gpio_pull_down_b(&mut config);
     config.disable_gpio_pull_down(GPIO::GPIO6);
 
@@ -111,7 +117,7 @@ fn test_disable_gpio_pull_down_gpio6() {
 
 #[test]
 fn test_disable_gpio_pull_down_gpio7() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
     enable_all_gpio_pull_down_b(&mut config);
     config.disable_gpio_pull_down(GPIO::GPIO7);
 
@@ -121,7 +127,7 @@ fn test_disable_gpio_pull_down_gpio7() {
 
 #[test]
 fn test_disable_gpio_pull_down_gpio8() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
     enable_all_gpio_pull_down_b(&mut config);
     config.disable_gpio_pull_down(GPIO::GPIO8);
 
@@ -131,7 +137,7 @@ fn test_disable_gpio_pull_down_gpio8() {
 
 #[test]
 fn test_disable_gpio_pull_down_gpio9() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
     enable_all_gpio_pull_down_b(&mut config);
     config.disable_gpio_pull_down(GPIO::GPIO9);
 
@@ -141,7 +147,7 @@ fn test_disable_gpio_pull_down_gpio9() {
 
 #[test]
 fn test_reference_power() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
 
     config.enable_reference_power();
     assert_eq!(0b1111_1100, config.register_a[0]);
@@ -154,7 +160,7 @@ fn test_reference_power() {
 
 #[test]
 fn test_toggle_discharge_timer() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
 
     config.enable_discharge_timer();
     assert_eq!(0b1111_1010, config.register_a[0]);
@@ -167,7 +173,7 @@ fn test_toggle_discharge_timer() {
 
 #[test]
 fn test_adc_modes() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
 
     config.set_alternative_adc_modes();
     assert_eq!(0b1111_1001, config.register_a[0]);
@@ -180,7 +186,7 @@ fn test_adc_modes() {
 
 #[test]
 fn test_set_uv_comp_voltage() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
 
     config.set_uv_comp_voltage(3_200_000).unwrap();
     assert_eq!(0b1100_1111, config.register_a[1]);
@@ -215,14 +221,14 @@ fn test_set_uv_comp_voltage() {
 
 #[test]
 fn test_set_uv_comp_voltage_out_of_range() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
     assert!(config.set_uv_comp_voltage(6_553_601).is_err());
     assert!(config.set_uv_comp_voltage(3199).is_err());
 }
 
 #[test]
 fn test_set_ov_comp_voltage() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
 
     config.set_ov_comp_voltage(4_160_000).unwrap();
     assert_eq!(0b1010_0010, config.register_a[3]);
@@ -257,14 +263,14 @@ fn test_set_ov_comp_voltage() {
 
 #[test]
 fn test_set_ov_comp_voltage_out_of_range() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
     assert!(config.set_ov_comp_voltage(6_552_001).is_err());
     assert!(config.set_ov_comp_voltage(1599).is_err());
 }
 
 #[test]
 fn test_discharge_cell() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
 
     config.discharge_cell(Cell::Cell1);
     assert_eq!(0b0000_0001, config.register_a[4]);
@@ -371,7 +377,7 @@ fn test_discharge_cell() {
 
 #[test]
 fn test_set_discharge_timeout() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
 
     config.set_discharge_timeout(DischargeTimeout::Disabled);
     assert_eq!(0b0000_0000, config.register_a[5]);
@@ -445,7 +451,7 @@ fn test_set_discharge_timeout() {
 
 #[test]
 fn test_force_digital_redundancy_fail() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
 
     config.force_digital_redundancy_fail();
     assert_eq!(0b0100_0000, config.register_b[1]);
@@ -454,7 +460,7 @@ fn test_force_digital_redundancy_fail() {
 
 #[test]
 fn test_set_digital_redundancy_path() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
 
     config.set_digital_redundancy_path(DigitalRedundancyPath::All);
     assert_eq!(0b0000_0000, config.register_b[1]);
@@ -480,7 +486,7 @@ fn test_set_digital_redundancy_path() {
 
 #[test]
 fn test_enable_discharge_monitor() {
-    let mut config = Configuration::default();
+    let mut config: Configuration<LTC6813> = Configuration::default();
 
     config.enable_discharge_monitor();
     assert_eq!(0b0000_1000, config.register_b[1]);
@@ -489,8 +495,8 @@ fn test_enable_discharge_monitor() {
 
 #[test]
 fn test_eq_false_register_a() {
-    let mut a = Configuration::default();
-    let b = Configuration::default();
+    let mut a: Configuration<LTC6813> = Configuration::default();
+    let b: Configuration<LTC6813> = Configuration::default();
 
     a.discharge_cell(Cell::Cell1);
     assert_ne!(a, b);
@@ -498,8 +504,8 @@ fn test_eq_false_register_a() {
 
 #[test]
 fn test_eq_false_register_b() {
-    let mut a = Configuration::default();
-    let b = Configuration::default();
+    let mut a: Configuration<LTC6813> = Configuration::default();
+    let b: Configuration<LTC6813> = Configuration::default();
 
     a.discharge_cell(Cell::Cell18);
     assert_ne!(a, b);
@@ -507,30 +513,386 @@ fn test_eq_false_register_b() {
 
 #[test]
 fn test_eq_true() {
-    let mut a = Configuration::default();
-    let mut b = Configuration::default();
+    let mut a: Configuration<LTC6813> = Configuration::default();
+    let mut b: Configuration<LTC6813> = Configuration::default();
 
     a.set_ov_comp_voltage(4_200_000).unwrap();
     b.set_ov_comp_voltage(4_200_000).unwrap();
     assert_eq!(a, b);
 }
 
-/// Asserts that all register slots, except one, match the default values
-fn assert_default(except: usize, config: &Configuration) {
+#[test]
+fn test_getters_default() {
+    let config: Configuration<LTC6813> = Configuration::default();
+
+    for index in 0..9 {
+        assert!(!config.gpio_pull_down_enabled(gpio(index)));
+    }
+
+    for index in 0..18 {
+        assert!(!config.cell_discharged(cell(index)));
+    }
+
+    assert!(!config.reference_power_enabled());
+    assert!(!config.discharge_timer_enabled());
+    assert!(!config.alternative_adc_modes());
+    assert_eq!(0, config.uv_comp_voltage());
+    assert_eq!(0, config.ov_comp_voltage());
+    assert_eq!(DischargeTimeout::Disabled, config.discharge_timeout());
+}
+
+#[test]
+fn test_gpio_pull_down_enabled() {
+    for index in 0..9 {
+        let mut config: Configuration<LTC6813> = Configuration::default();
+        config.enable_gpio_pull_down(gpio(index));
+
+        for other in 0..9 {
+            assert_eq!(index == other, config.gpio_pull_down_enabled(gpio(other)));
+        }
+
+        config.disable_gpio_pull_down(gpio(index));
+        assert!(!config.gpio_pull_down_enabled(gpio(index)));
+    }
+}
+
+#[test]
+fn test_flag_getters() {
+    let mut config: Configuration<LTC6813> = Configuration::default();
+
+    config.enable_reference_power();
+    assert!(config.reference_power_enabled());
+    assert!(!config.discharge_timer_enabled());
+    assert!(!config.alternative_adc_modes());
+
+    config.enable_discharge_timer();
+    assert!(config.discharge_timer_enabled());
+
+    config.set_alternative_adc_modes();
+    assert!(config.alternative_adc_modes());
+
+    config.disable_reference_power();
+    config.disable_discharge_timer();
+    config.set_default_adc_modes();
+    assert!(!config.reference_power_enabled());
+    assert!(!config.discharge_timer_enabled());
+    assert!(!config.alternative_adc_modes());
+}
+
+#[test]
+fn test_comp_voltage_getters() {
+    let mut config: Configuration<LTC6813> = Configuration::default();
+
+    config.set_uv_comp_voltage(3_000_000).unwrap();
+    config.set_ov_comp_voltage(4_200_000).unwrap();
+    assert_eq!(3_000_000, config.uv_comp_voltage());
+    assert_eq!(4_200_000, config.ov_comp_voltage());
+
+    config.set_uv_comp_voltage(3200).unwrap();
+    config.set_ov_comp_voltage(6_552_000).unwrap();
+    assert_eq!(3200, config.uv_comp_voltage());
+    assert_eq!(6_552_000, config.ov_comp_voltage());
+
+    config.set_uv_comp_voltage(6_553_600).unwrap();
+    config.set_ov_comp_voltage(1600).unwrap();
+    assert_eq!(6_553_600, config.uv_comp_voltage());
+    assert_eq!(1600, config.ov_comp_voltage());
+
+    config.set_uv_comp_voltage(0).unwrap();
+    config.set_ov_comp_voltage(0).unwrap();
+    assert_eq!(0, config.uv_comp_voltage());
+    assert_eq!(0, config.ov_comp_voltage());
+}
+
+#[test]
+fn test_cell_discharged() {
+    for index in 0..18 {
+        let mut config: Configuration<LTC6813> = Configuration::default();
+        config.discharge_cell(cell(index));
+
+        for other in 0..18 {
+            assert_eq!(index == other, config.cell_discharged(cell(other)));
+        }
+    }
+}
+
+#[test]
+fn test_discharge_timeout_getter() {
+    let timeouts = [
+        DischargeTimeout::Disabled,
+        DischargeTimeout::HalfMinute,
+        DischargeTimeout::OneMinute,
+        DischargeTimeout::TwoMinutes,
+        DischargeTimeout::ThreeMinutes,
+        DischargeTimeout::FourMinutes,
+        DischargeTimeout::FiveMinutes,
+        DischargeTimeout::TenMinutes,
+        DischargeTimeout::FifteenMinutes,
+        DischargeTimeout::TwentyMinutes,
+        DischargeTimeout::ThirtyMinutes,
+        DischargeTimeout::FortyMinutes,
+        DischargeTimeout::SixtyMinutes,
+        DischargeTimeout::SeventyFiveMinutes,
+        DischargeTimeout::NinetyMinutes,
+        DischargeTimeout::TwoHours,
+    ];
+
+    for timeout in timeouts {
+        let mut config: Configuration<LTC6813> = Configuration::default();
+        config.discharge_cell(Cell::Cell12);
+        config.set_discharge_timeout(timeout);
+
+        assert_eq!(timeout, config.discharge_timeout());
+        assert!(config.cell_discharged(Cell::Cell12));
+    }
+}
+
+#[test]
+fn test_pwm_default() {
+    let config: PWMConfiguration<LTC6813> = PWMConfiguration::default();
+    assert_eq!([0xFF; 9], config.register);
+    assert_eq!(Some(15), config.duty_cycle(Channel::Cell1));
+    assert_eq!(Some(15), config.duty_cycle(Channel::Cell12));
+}
+
+#[test]
+fn test_pwm_set_duty_cycle() {
+    let mut config: PWMConfiguration<LTC6813> = PWMConfiguration::default();
+
+    config.set_duty_cycle(Channel::Cell1, 0x1).unwrap();
+    config.set_duty_cycle(Channel::Cell2, 0x2).unwrap();
+    config.set_duty_cycle(Channel::Cell5, 0x0).unwrap();
+    config.set_duty_cycle(Channel::Cell11, 0xA).unwrap();
+    config.set_duty_cycle(Channel::Cell12, 0x5).unwrap();
+    assert_eq!([0x21, 0xFF, 0xF0, 0xFF, 0xFF, 0x5A, 0xFF, 0xFF, 0xFF], config.register);
+
+    assert_eq!(Some(0x1), config.duty_cycle(Channel::Cell1));
+    assert_eq!(Some(0x2), config.duty_cycle(Channel::Cell2));
+    assert_eq!(Some(0xF), config.duty_cycle(Channel::Cell3));
+    assert_eq!(Some(0x0), config.duty_cycle(Channel::Cell5));
+    assert_eq!(Some(0xA), config.duty_cycle(Channel::Cell11));
+    assert_eq!(Some(0x5), config.duty_cycle(Channel::Cell12));
+
+    // Overwriting previous value
+    config.set_duty_cycle(Channel::Cell12, 0xC).unwrap();
+    assert_eq!(0xCA, config.register[5]);
+}
+
+#[test]
+fn test_pwm_set_duty_cycle_ltc6810() {
+    let mut config: PWMConfiguration<LTC6810> = PWMConfiguration::default();
+
+    config.set_duty_cycle(ltc6810::Channel::Cell6, 0x3).unwrap();
+    assert_eq!([0xFF, 0xFF, 0x3F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF], config.register);
+    assert_eq!(Some(0x3), config.duty_cycle(ltc6810::Channel::Cell6));
+}
+
+#[test]
+fn test_pwm_set_duty_cycle_psb() {
+    let mut config: PWMConfiguration<LTC6813> = PWMConfiguration::default();
+
+    config.set_duty_cycle(Channel::Cell13, 0x1).unwrap();
+    config.set_duty_cycle(Channel::Cell16, 0x2).unwrap();
+    config.set_duty_cycle(Channel::Cell18, 0x3).unwrap();
+    assert_eq!([0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF1, 0x2F, 0x3F], config.register);
+
+    assert_eq!(Some(0x1), config.duty_cycle(Channel::Cell13));
+    assert_eq!(Some(0xF), config.duty_cycle(Channel::Cell14));
+    assert_eq!(Some(0x2), config.duty_cycle(Channel::Cell16));
+    assert_eq!(Some(0x3), config.duty_cycle(Channel::Cell18));
+}
+
+#[test]
+fn test_pwm_set_duty_cycle_out_of_range() {
+    let mut config: PWMConfiguration<LTC6813> = PWMConfiguration::default();
+
+    assert_eq!(
+        Err(DutyCycleError::OutOfRange),
+        config.set_duty_cycle(Channel::Cell1, 16)
+    );
+    assert_eq!([0xFF; 9], config.register);
+}
+
+#[test]
+fn test_pwm_set_duty_cycle_invalid_cell() {
+    let mut config: PWMConfiguration<LTC6813> = PWMConfiguration::default();
+
+    assert_eq!(
+        Err(DutyCycleError::InvalidCell),
+        config.set_duty_cycle(Channel::GPIO1, 1)
+    );
+    assert_eq!(None, config.duty_cycle(Channel::GPIO1));
+    assert_eq!([0xFF; 9], config.register);
+}
+
+#[test]
+fn test_s_control_default() {
+    let config: SControlConfiguration<LTC6813> = SControlConfiguration::default();
+    assert_eq!([0x0; 9], config.register);
+    assert_eq!(Some(SPinControl::High), config.control(Channel::Cell1));
+}
+
+#[test]
+fn test_s_control_set_control() {
+    let mut config: SControlConfiguration<LTC6813> = SControlConfiguration::default();
+
+    config.set_control(Channel::Cell1, SPinControl::Pulses(1)).unwrap();
+    config.set_control(Channel::Cell2, SPinControl::Pulses(7)).unwrap();
+    config.set_control(Channel::Cell6, SPinControl::Low).unwrap();
+    config.set_control(Channel::Cell11, SPinControl::Pulses(4)).unwrap();
+    assert_eq!([0x71, 0x00, 0x80, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00], config.register);
+
+    assert_eq!(Some(SPinControl::Pulses(1)), config.control(Channel::Cell1));
+    assert_eq!(Some(SPinControl::Pulses(7)), config.control(Channel::Cell2));
+    assert_eq!(Some(SPinControl::Low), config.control(Channel::Cell6));
+    assert_eq!(Some(SPinControl::Pulses(4)), config.control(Channel::Cell11));
+    assert_eq!(Some(SPinControl::High), config.control(Channel::Cell12));
+
+    // Overwriting previous value
+    config.set_control(Channel::Cell6, SPinControl::High).unwrap();
+    assert_eq!(0x00, config.register[2]);
+}
+
+#[test]
+fn test_s_control_set_control_psb() {
+    let mut config: SControlConfiguration<LTC6812> = SControlConfiguration::default();
+
+    config.set_control(ltc6812::Channel::Cell13, SPinControl::Low).unwrap();
+    config.set_control(ltc6812::Channel::Cell15, SPinControl::Pulses(5)).unwrap();
+    assert_eq!([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x05, 0x00], config.register);
+
+    assert_eq!(Some(SPinControl::Low), config.control(ltc6812::Channel::Cell13));
+    assert_eq!(Some(SPinControl::High), config.control(ltc6812::Channel::Cell14));
+    assert_eq!(Some(SPinControl::Pulses(5)), config.control(ltc6812::Channel::Cell15));
+}
+
+#[test]
+fn test_s_control_set_control_out_of_range() {
+    let mut config: SControlConfiguration<LTC6813> = SControlConfiguration::default();
+
+    assert_eq!(
+        Err(SControlError::OutOfRange),
+        config.set_control(Channel::Cell1, SPinControl::Pulses(0))
+    );
+    assert_eq!(
+        Err(SControlError::OutOfRange),
+        config.set_control(Channel::Cell1, SPinControl::Pulses(8))
+    );
+    assert_eq!([0x0; 9], config.register);
+}
+
+#[test]
+fn test_s_control_set_control_invalid_cell() {
+    let mut config: SControlConfiguration<LTC6813> = SControlConfiguration::default();
+
+    assert_eq!(
+        Err(SControlError::InvalidCell),
+        config.set_control(Channel::GPIO2, SPinControl::Low)
+    );
+    assert_eq!(None, config.control(Channel::GPIO2));
+    assert_eq!([0x0; 9], config.register);
+}
+
+#[test]
+fn test_s_pin_control_from_u8() {
+    assert_eq!(SPinControl::High, SPinControl::from(0x0));
+    assert_eq!(SPinControl::Pulses(1), SPinControl::from(0x1));
+    assert_eq!(SPinControl::Pulses(7), SPinControl::from(0x7));
+    assert_eq!(SPinControl::Low, SPinControl::from(0x8));
+    assert_eq!(SPinControl::Low, SPinControl::from(0xF));
+    assert_eq!(SPinControl::Pulses(3), SPinControl::from(0xF3));
+}
+
+#[test]
+fn test_ltc6810_getters_default() {
+    let config: Configuration<LTC6810> = Configuration::default();
+
+    assert!(!config.s0_discharged());
+    assert!(!config.multi_calibration_enabled());
+    assert!(!config.redundant_s_pin_conversion_enabled());
+    assert!(!config.digital_redundancy_fail_forced());
+    assert!(!config.discharge_monitor_enabled());
+}
+
+#[test]
+fn test_ltc6810_discharge_cell() {
+    let mut config: Configuration<LTC6810> = Configuration::default();
+
+    config.discharge_cell(ltc6810::Cell::Cell1);
+    config.discharge_cell(ltc6810::Cell::Cell6);
+    assert_eq!(0b0010_0001, config.register_a[4]);
+    assert!(config.cell_discharged(ltc6810::Cell::Cell6));
+
+    config.discharge_s0();
+    assert_eq!(0b1010_0001, config.register_a[4]);
+    assert!(config.s0_discharged());
+}
+
+#[test]
+fn test_ltc6810_gpio_pull_down() {
+    let mut config: Configuration<LTC6810> = Configuration::default();
+
+    config.enable_gpio_pull_down(ltc6810::GPIO::GPIO1);
+    config.enable_gpio_pull_down(ltc6810::GPIO::GPIO4);
+    assert_eq!(0b1011_0000, config.register_a[0]);
+    assert!(config.gpio_pull_down_enabled(ltc6810::GPIO::GPIO4));
+}
+
+#[test]
+fn test_ltc6810_multi_calibration() {
+    let mut config: Configuration<LTC6810> = Configuration::default();
+
+    config.enable_multi_calibration();
+    assert_eq!(0b0100_0000, config.register_a[4]);
+    assert!(config.multi_calibration_enabled());
+
+    config.disable_multi_calibration();
+    assert_eq!(0x0, config.register_a[4]);
+}
+
+#[test]
+fn test_ltc6810_register_5_flags() {
+    let mut config: Configuration<LTC6810> = Configuration::default();
+
+    config.set_discharge_timeout(DischargeTimeout::TwoHours);
+    assert_eq!(0b1111_0000, config.register_a[5]);
+
+    config.enable_redundant_s_pin_conversion();
+    assert_eq!(0b1111_1000, config.register_a[5]);
+    assert!(config.redundant_s_pin_conversion_enabled());
+
+    config.force_digital_redundancy_fail();
+    assert_eq!(0b1111_1100, config.register_a[5]);
+    assert!(config.digital_redundancy_fail_forced());
+
+    config.enable_discharge_monitor();
+    assert_eq!(0b1111_1101, config.register_a[5]);
+    assert!(config.discharge_monitor_enabled());
+
+    config.disable_redundant_s_pin_conversion();
+    config.disable_discharge_monitor();
+    assert_eq!(0b1111_0100, config.register_a[5]);
+    assert_eq!(DischargeTimeout::TwoHours, config.discharge_timeout());
+
+    // Register B is not used by LTC6810
+    assert_eq!(Configuration::<LTC6810>::default().register_b, config.register_b);
+}
+
+fn assert_default(except: usize, config: &Configuration<LTC6813>) {
     let mut actual = [0u8; 12];
     actual[..6].clone_from_slice(&config.register_a);
     actual[6..].clone_from_slice(&config.register_b);
 
     let mut default = [0u8; 12];
-    default[..6].clone_from_slice(&Configuration::default().register_a);
-    default[6..].clone_from_slice(&Configuration::default().register_b);
+    default[..6].clone_from_slice(&Configuration::<LTC6813>::default().register_a);
+    default[6..].clone_from_slice(&Configuration::<LTC6813>::default().register_b);
 
     actual[except] = default[except];
     assert_eq!(actual, default);
 }
 
 /// Enables pull-down of all GPIO pins in register A
-fn enable_all_gpio_pull_down_a(config: &mut Configuration) {
+fn enable_all_gpio_pull_down_a(config: &mut Configuration<LTC6813>) {
     config.enable_gpio_pull_down(GPIO::GPIO1);
     config.enable_gpio_pull_down(GPIO::GPIO2);
     config.enable_gpio_pull_down(GPIO::GPIO3);
@@ -539,9 +901,46 @@ fn enable_all_gpio_pull_down_a(config: &mut Configuration) {
 }
 
 /// Enables pull-down of all GPIO pins in register B
-fn enable_all_gpio_pull_down_b(config: &mut Configuration) {
+fn enable_all_gpio_pull_down_b(config: &mut Configuration<LTC6813>) {
     config.enable_gpio_pull_down(GPIO::GPIO6);
     config.enable_gpio_pull_down(GPIO::GPIO7);
     config.enable_gpio_pull_down(GPIO::GPIO8);
     config.enable_gpio_pull_down(GPIO::GPIO9);
 }
+
+fn cell(index: usize) -> Cell {
+    [
+        Cell::Cell1,
+        Cell::Cell2,
+        Cell::Cell3,
+        Cell::Cell4,
+        Cell::Cell5,
+        Cell::Cell6,
+        Cell::Cell7,
+        Cell::Cell8,
+        Cell::Cell9,
+        Cell::Cell10,
+        Cell::Cell11,
+        Cell::Cell12,
+        Cell::Cell13,
+        Cell::Cell14,
+        Cell::Cell15,
+        Cell::Cell16,
+        Cell::Cell17,
+        Cell::Cell18,
+    ][index]
+}
+
+fn gpio(index: usize) -> GPIO {
+    [
+        GPIO::GPIO1,
+        GPIO::GPIO2,
+        GPIO::GPIO3,
+        GPIO::GPIO4,
+        GPIO::GPIO5,
+        GPIO::GPIO6,
+        GPIO::GPIO7,
+        GPIO::GPIO8,
+        GPIO::GPIO9,
+    ][index]
+}